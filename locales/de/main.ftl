@@ -1,11 +1,101 @@
 settings = Einstellungen
 copy_to_clipboard = In Zwischenablage kopieren
 main = Hauptfenster
+network = Netzwerk
+permissions = Berechtigungen
+color = Farbe
+timestamp = Zeit
+mask = Maske
 decimal = Dezimal
 hexadecimal = Hexadezimal
 octal = Oktal
+binary = Binär
 choose_a_theme = Thema auswählen:
 choose_a_language = Sprache auswählen:
 light = Hell
 dark = Dunkel
-custom = Benutzerspezifisch
\ No newline at end of file
+custom = Benutzerspezifisch
+copy_formats = Kopierformate:
+number_format = Zahlenformat:
+language_name = Deutsch
+title = Binärrechner
+not = Nicht
+paste = Einfügen
+copy_all = Alles kopieren
+copy = Kopieren
+signed = vorzeichenbehaftet
+unsigned = vorzeichenlos
+bit_tooltip = Bit { $bit } [{ $value }]
+copied = { $text } kopiert
+pasted = { $layout } eingefügt
+paste_unreadable = „{ $text }“ ist nicht lesbar
+paste_ambiguous = Mehrdeutig, gelesen als { $layout }; möglich wäre auch { $others }
+layout_verilog_literal = Verilog-Literal
+layout_vhdl_literal = VHDL-Literal
+layout_motorola_byte_string = Motorola-Bytestring
+layout_intel_byte_string = Intel-Bytestring
+layout_motorola_byte_array = Motorola-Byte-Array
+layout_intel_byte_array = Intel-Byte-Array
+layout_motorola_bytes = Motorola-Bytes
+layout_intel_bytes = Intel-Bytes
+layout_hexadecimal_blocks = Hexadezimalblöcke
+layout_grouped_decimal = gruppierte Dezimalzahl
+layout_ipv4_address = IPv4-Adresse
+layout_hexadecimal = hexadezimal
+layout_binary = binär
+layout_octal = oktal
+layout_signed_decimal = vorzeichenbehaftete Dezimalzahl
+layout_decimal = dezimal
+layout_c_octal = C-Oktal
+layout_prefixed_decimal = Dezimalzahl mit Präfix
+no_clipboard = Keine Zwischenablage: { $reason }
+clipboard_read_failed = Die Zwischenablage ist nicht lesbar: { $reason }
+clipboard_write_failed = Die Zwischenablage ist nicht beschreibbar: { $reason }
+settings_invalid = Ungültige Einstellungen, es gelten die Vorgaben. Die Datei wurde nach { $backup } verschoben: { $reason }
+settings_write_failed = Die Einstellungen sind nicht speicherbar: { $reason }
+file_error = { $path }: { $reason }
+theme_file_invalid = { $path } ist keine Themendatei: { $reason }
+ipv4 = IPv4
+netmask = Netzmaske
+wildcard_mask = Wildcard-Maske
+broadcast = Broadcast
+host_range = Hostbereich
+hosts = Hosts
+user = Benutzer
+group = Gruppe
+other = Andere
+invalid_mode = Ungültiger Modus
+invalid_colour = Ungültige Farbe
+utc = UTC
+local = Lokal
+invalid_date = Ungültiges Datum
+gps_week = GPS-Woche { $week }, { $seconds } s
+unix_seconds = Unix-Sekunden
+unix_milliseconds = Unix-Millisekunden
+fat_date_time = FAT-Datum/-Zeit
+ntp_seconds = NTP-Sekunden
+ntp_short = NTP kurz
+gps_time = GPS-Zeit
+field_name = Feldname
+add_field = Feld hinzufügen
+from_value = Aus { $value }
+name = Name
+separator = Trennzeichen
+prefix = Präfix
+suffix = Suffix
+import = Importieren
+export = Exportieren
+imported = { $name } importiert
+exported = Nach { $path } exportiert
+custom_theme_name = Benutzerspezifisch { $number }
+theme_name_taken = Es gibt bereits ein Thema namens { $name }
+background = Hintergrund
+text = Text
+primary = Primär
+success = Erfolg
+danger = Gefahr
+plain_text = Text
+locale = Gebietsschema
+ui_scale = Skalierung
+follow_language = (Sprache)
+tui_help = Tab Feld · ←/→ Bit · Leertaste umschalten · 0-f Tastenfeld · Rücktaste · < > schieben · ~ nicht · s Vorzeichen · ↑/↓ Format · Enter kopieren · p einfügen · q beenden
@@ -1,11 +1,101 @@
 settings = Settings
 copy_to_clipboard = Copy to clipboard
 main = Main
+network = Network
+permissions = Permissions
+color = Colour
+timestamp = Time
+mask = Mask
 decimal = Decimal
 hexadecimal = Hexadecimal
 octal = Octal
+binary = Binary
 choose_a_theme = Choose a theme:
 choose_a_language = Choose a language:
 light = Light
 dark = Dark
-custom = Custon
\ No newline at end of file
+custom = Custom
+copy_formats = Copy formats:
+number_format = Number format:
+language_name = English
+title = Binary Calculator
+not = Not
+paste = Paste
+copy_all = Copy all
+copy = Copy
+signed = signed
+unsigned = unsigned
+bit_tooltip = Bit { $bit } [{ $value }]
+copied = Copied { $text }
+pasted = Pasted { $layout }
+paste_unreadable = Cannot read "{ $text }"
+paste_ambiguous = Ambiguous, read as { $layout }; could also be { $others }
+layout_verilog_literal = Verilog literal
+layout_vhdl_literal = VHDL literal
+layout_motorola_byte_string = Motorola byte string
+layout_intel_byte_string = Intel byte string
+layout_motorola_byte_array = Motorola byte array
+layout_intel_byte_array = Intel byte array
+layout_motorola_bytes = Motorola bytes
+layout_intel_bytes = Intel bytes
+layout_hexadecimal_blocks = hexadecimal blocks
+layout_grouped_decimal = grouped decimal
+layout_ipv4_address = IPv4 address
+layout_hexadecimal = hexadecimal
+layout_binary = binary
+layout_octal = octal
+layout_signed_decimal = signed decimal
+layout_decimal = decimal
+layout_c_octal = C octal
+layout_prefixed_decimal = prefixed decimal
+no_clipboard = No clipboard: { $reason }
+clipboard_read_failed = Cannot read the clipboard: { $reason }
+clipboard_write_failed = Cannot write the clipboard: { $reason }
+settings_invalid = Invalid settings, using the defaults. The file was moved to { $backup }: { $reason }
+settings_write_failed = Cannot write the settings: { $reason }
+file_error = { $path }: { $reason }
+theme_file_invalid = { $path } is no theme file: { $reason }
+ipv4 = IPv4
+netmask = Netmask
+wildcard_mask = Wildcard mask
+broadcast = Broadcast
+host_range = Host range
+hosts = Hosts
+user = User
+group = Group
+other = Other
+invalid_mode = Invalid mode
+invalid_colour = Invalid colour
+utc = UTC
+local = Local
+invalid_date = Invalid date
+gps_week = GPS week { $week }, { $seconds } s
+unix_seconds = Unix seconds
+unix_milliseconds = Unix milliseconds
+fat_date_time = FAT date/time
+ntp_seconds = NTP seconds
+ntp_short = NTP short
+gps_time = GPS time
+field_name = Field name
+add_field = Add field
+from_value = From { $value }
+name = Name
+separator = Separator
+prefix = Prefix
+suffix = Suffix
+import = Import
+export = Export
+imported = Imported { $name }
+exported = Exported to { $path }
+custom_theme_name = Custom { $number }
+theme_name_taken = There is already a theme named { $name }
+background = Background
+text = Text
+primary = Primary
+success = Success
+danger = Danger
+plain_text = Text
+locale = Locale
+ui_scale = Scale
+follow_language = (language)
+tui_help = Tab field · ←/→ bit · Space toggle · 0-f keypad · Backspace · < > shift · ~ not · s sign · ↑/↓ format · Enter copy · p paste · q quit
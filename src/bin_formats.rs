@@ -0,0 +1,62 @@
+use crate::format_template::FormatTemplate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BinFormats {
+    #[default]
+    Plain,
+    Nibbles,
+    Bytes,
+    WithB,
+    WithBWithUnderscores,
+}
+
+impl BinFormats {
+    pub const ALL: [BinFormats; 5] = [
+        BinFormats::Plain,
+        BinFormats::Nibbles,
+        BinFormats::Bytes,
+        BinFormats::WithB,
+        BinFormats::WithBWithUnderscores,
+    ];
+
+    /// The name of the format on the command line, e.g. `bin-nibbles`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            BinFormats::Plain => "bin",
+            BinFormats::Nibbles => "bin-nibbles",
+            BinFormats::Bytes => "bin-bytes",
+            BinFormats::WithB => "bin-0b",
+            BinFormats::WithBWithUnderscores => "bin-0b-underscores",
+        }
+    }
+
+    /// The template which produces this format.
+    pub fn template(&self) -> FormatTemplate {
+        let name = self.to_string();
+        match self {
+            BinFormats::Plain => FormatTemplate::new(&name, 32, "", "").binary(),
+            BinFormats::Nibbles => FormatTemplate::new(&name, 4, " ", "").binary(),
+            BinFormats::Bytes => FormatTemplate::new(&name, 8, " ", "").binary(),
+            BinFormats::WithB => FormatTemplate::new(&name, 32, "", "0b").binary(),
+            BinFormats::WithBWithUnderscores => FormatTemplate::new(&name, 4, "_", "")
+                .binary()
+                .brackets("0b", ""),
+        }
+    }
+}
+
+impl std::fmt::Display for BinFormats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                BinFormats::Plain => "00000000…11111111",
+                BinFormats::Nibbles => "0000 0000 … 1111",
+                BinFormats::Bytes => "00000000 … 11111111",
+                BinFormats::WithB => "0b00000000…11111111",
+                BinFormats::WithBWithUnderscores => "0b0000_0000_…_1111",
+            }
+        )
+    }
+}
@@ -1,10 +1,13 @@
 use iced::widget::tooltip::Position;
 use iced::Alignment;
 use iced_native::{
-    column, renderer, row,
+    column, renderer,
     widget::{checkbox, text, tooltip, Row, Tree},
     Element, Length, Shell, Widget,
 };
+use iced_style::theme;
+
+use crate::translations::tr_args;
 
 /// The default text size.
 const DEFAULT_TEXT_SIZE: u16 = 16;
@@ -14,13 +17,48 @@ const DEFAULT_PADDING: u16 = 5;
 const DEFAULT_SPACING: u16 = 2;
 /// The default spacing between the groups of checkboxes.
 const DEFAULT_GROUP_SPACING: u16 = 6;
+/// The message of each bit, by its number.
+const BIT_MESSAGES: [fn(bool) -> BinaryFieldWidgetMessage; 32] = [
+    BinaryFieldWidgetMessage::Bit0,
+    BinaryFieldWidgetMessage::Bit1,
+    BinaryFieldWidgetMessage::Bit2,
+    BinaryFieldWidgetMessage::Bit3,
+    BinaryFieldWidgetMessage::Bit4,
+    BinaryFieldWidgetMessage::Bit5,
+    BinaryFieldWidgetMessage::Bit6,
+    BinaryFieldWidgetMessage::Bit7,
+    BinaryFieldWidgetMessage::Bit8,
+    BinaryFieldWidgetMessage::Bit9,
+    BinaryFieldWidgetMessage::Bit10,
+    BinaryFieldWidgetMessage::Bit11,
+    BinaryFieldWidgetMessage::Bit12,
+    BinaryFieldWidgetMessage::Bit13,
+    BinaryFieldWidgetMessage::Bit14,
+    BinaryFieldWidgetMessage::Bit15,
+    BinaryFieldWidgetMessage::Bit16,
+    BinaryFieldWidgetMessage::Bit17,
+    BinaryFieldWidgetMessage::Bit18,
+    BinaryFieldWidgetMessage::Bit19,
+    BinaryFieldWidgetMessage::Bit20,
+    BinaryFieldWidgetMessage::Bit21,
+    BinaryFieldWidgetMessage::Bit22,
+    BinaryFieldWidgetMessage::Bit23,
+    BinaryFieldWidgetMessage::Bit24,
+    BinaryFieldWidgetMessage::Bit25,
+    BinaryFieldWidgetMessage::Bit26,
+    BinaryFieldWidgetMessage::Bit27,
+    BinaryFieldWidgetMessage::Bit28,
+    BinaryFieldWidgetMessage::Bit29,
+    BinaryFieldWidgetMessage::Bit30,
+    BinaryFieldWidgetMessage::Bit31,
+];
 
 pub struct BinaryFieldWidget<'a, Message, Renderer>
 where
     Renderer: iced_native::Renderer + iced_native::text::Renderer,
     Renderer::Theme: text::StyleSheet
         + iced_native::widget::container::StyleSheet
-        + iced_native::widget::checkbox::StyleSheet,
+        + iced_native::widget::checkbox::StyleSheet<Style = theme::Checkbox>,
 {
     value: u32,
     /// The width of the [`BinaryFieldWidget`](BinaryFieldWidget)
@@ -37,6 +75,10 @@ where
     spacing: u16,
     /// The spacing between the group of checkboxes of the [`BinaryFieldWidget`](BinaryFieldWidget)
     group_spacing: u16,
+    /// The bits of the [`BinaryFieldWidget`](BinaryFieldWidget) drawn in the highlight style
+    highlight: u32,
+    /// Whether the bits of the [`BinaryFieldWidget`](BinaryFieldWidget) wrap into two rows of 16
+    wrap: bool,
     /// The underlying element of the [`BinaryFieldWidget`](BinaryFieldWidget)
     content: Row<'a, BinaryFieldWidgetMessage, Renderer>,
     /// The on_change event of the [`BinaryFieldWidget`](BinaryFieldWidget).
@@ -49,7 +91,7 @@ where
     Renderer: 'a + iced_native::Renderer + iced_native::text::Renderer,
     Renderer::Theme: text::StyleSheet
         + iced_native::widget::container::StyleSheet
-        + iced_native::widget::checkbox::StyleSheet,
+        + iced_native::widget::checkbox::StyleSheet<Style = theme::Checkbox>,
 {
     pub fn new<F>(value: u32, on_change: F) -> Self
     where
@@ -64,12 +106,16 @@ where
             padding: DEFAULT_PADDING,
             spacing: DEFAULT_SPACING,
             group_spacing: DEFAULT_GROUP_SPACING,
+            highlight: 0,
+            wrap: false,
             content: Self::create_content(
                 value,
                 DEFAULT_SPACING,
                 DEFAULT_GROUP_SPACING,
                 DEFAULT_TEXT_SIZE,
                 Length::Units(40),
+                0,
+                false,
             ),
             on_change: Box::new(on_change),
             messages: Vec::new(),
@@ -99,6 +145,8 @@ where
             self.group_spacing,
             self.text_size,
             self.height,
+            self.highlight,
+            self.wrap,
         );
         self
     }
@@ -126,6 +174,8 @@ where
             self.group_spacing,
             self.text_size,
             self.height,
+            self.highlight,
+            self.wrap,
         );
         self
     }
@@ -146,6 +196,8 @@ where
             self.group_spacing,
             self.text_size,
             self.height,
+            self.highlight,
+            self.wrap,
         );
         self
     }
@@ -160,6 +212,42 @@ where
             self.group_spacing,
             self.text_size,
             self.height,
+            self.highlight,
+            self.wrap,
+        );
+        self
+    }
+
+    /// Sets the bits of the [`BinaryFieldWidget`](BinaryFieldWidget) which are drawn
+    /// in the highlight style, e.g. the bits of a subnet mask.
+    #[must_use]
+    pub fn highlight(mut self, mask: u32) -> Self {
+        self.highlight = mask;
+        self.content = Self::create_content(
+            self.value,
+            self.spacing,
+            self.group_spacing,
+            self.text_size,
+            self.height,
+            self.highlight,
+            self.wrap,
+        );
+        self
+    }
+
+    /// Wraps the bits of the [`BinaryFieldWidget`](BinaryFieldWidget) into two rows of 16,
+    /// e.g. for narrow windows.
+    #[must_use]
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self.content = Self::create_content(
+            self.value,
+            self.spacing,
+            self.group_spacing,
+            self.text_size,
+            self.height,
+            self.highlight,
+            self.wrap,
         );
         self
     }
@@ -170,204 +258,48 @@ where
         group_spacing: u16,
         text_size: u16,
         height: Length,
+        highlight: u32,
+        wrap: bool,
     ) -> Row<'a, BinaryFieldWidgetMessage, Renderer> {
-        let bit0_checkbox =
-            checkbox("", get_bit(value, 0), BinaryFieldWidgetMessage::Bit0).spacing(0);
-        let bit1_checkbox =
-            checkbox("", get_bit(value, 1), BinaryFieldWidgetMessage::Bit1).spacing(0);
-        let bit2_checkbox =
-            checkbox("", get_bit(value, 2), BinaryFieldWidgetMessage::Bit2).spacing(0);
-        let bit3_checkbox =
-            checkbox("", get_bit(value, 3), BinaryFieldWidgetMessage::Bit3).spacing(0);
-        let bit4_checkbox =
-            checkbox("", get_bit(value, 4), BinaryFieldWidgetMessage::Bit4).spacing(0);
-        let bit5_checkbox =
-            checkbox("", get_bit(value, 5), BinaryFieldWidgetMessage::Bit5).spacing(0);
-        let bit6_checkbox =
-            checkbox("", get_bit(value, 6), BinaryFieldWidgetMessage::Bit6).spacing(0);
-        let bit7_checkbox =
-            checkbox("", get_bit(value, 7), BinaryFieldWidgetMessage::Bit7).spacing(0);
-        let bit8_checkbox =
-            checkbox("", get_bit(value, 8), BinaryFieldWidgetMessage::Bit8).spacing(0);
-        let bit9_checkbox =
-            checkbox("", get_bit(value, 9), BinaryFieldWidgetMessage::Bit9).spacing(0);
-        let bit10_checkbox =
-            checkbox("", get_bit(value, 10), BinaryFieldWidgetMessage::Bit10).spacing(0);
-        let bit11_checkbox =
-            checkbox("", get_bit(value, 11), BinaryFieldWidgetMessage::Bit11).spacing(0);
-        let bit12_checkbox =
-            checkbox("", get_bit(value, 12), BinaryFieldWidgetMessage::Bit12).spacing(0);
-        let bit13_checkbox =
-            checkbox("", get_bit(value, 13), BinaryFieldWidgetMessage::Bit13).spacing(0);
-        let bit14_checkbox =
-            checkbox("", get_bit(value, 14), BinaryFieldWidgetMessage::Bit14).spacing(0);
-        let bit15_checkbox =
-            checkbox("", get_bit(value, 15), BinaryFieldWidgetMessage::Bit15).spacing(0);
-        let bit16_checkbox =
-            checkbox("", get_bit(value, 16), BinaryFieldWidgetMessage::Bit16).spacing(0);
-        let bit17_checkbox =
-            checkbox("", get_bit(value, 17), BinaryFieldWidgetMessage::Bit17).spacing(0);
-        let bit18_checkbox =
-            checkbox("", get_bit(value, 18), BinaryFieldWidgetMessage::Bit18).spacing(0);
-        let bit19_checkbox =
-            checkbox("", get_bit(value, 19), BinaryFieldWidgetMessage::Bit19).spacing(0);
-        let bit20_checkbox =
-            checkbox("", get_bit(value, 20), BinaryFieldWidgetMessage::Bit20).spacing(0);
-        let bit21_checkbox =
-            checkbox("", get_bit(value, 21), BinaryFieldWidgetMessage::Bit21).spacing(0);
-        let bit22_checkbox =
-            checkbox("", get_bit(value, 22), BinaryFieldWidgetMessage::Bit22).spacing(0);
-        let bit23_checkbox =
-            checkbox("", get_bit(value, 23), BinaryFieldWidgetMessage::Bit23).spacing(0);
-        let bit24_checkbox =
-            checkbox("", get_bit(value, 24), BinaryFieldWidgetMessage::Bit24).spacing(0);
-        let bit25_checkbox =
-            checkbox("", get_bit(value, 25), BinaryFieldWidgetMessage::Bit25).spacing(0);
-        let bit26_checkbox =
-            checkbox("", get_bit(value, 26), BinaryFieldWidgetMessage::Bit26).spacing(0);
-        let bit27_checkbox =
-            checkbox("", get_bit(value, 27), BinaryFieldWidgetMessage::Bit27).spacing(0);
-        let bit28_checkbox =
-            checkbox("", get_bit(value, 28), BinaryFieldWidgetMessage::Bit28).spacing(0);
-        let bit29_checkbox =
-            checkbox("", get_bit(value, 29), BinaryFieldWidgetMessage::Bit29).spacing(0);
-        let bit30_checkbox =
-            checkbox("", get_bit(value, 30), BinaryFieldWidgetMessage::Bit30).spacing(0);
-        let bit31_checkbox =
-            checkbox("", get_bit(value, 31), BinaryFieldWidgetMessage::Bit31).spacing(0);
-        row![
-            row![
-                column![
-                    text("31").size(text_size),
-                    tooltip(bit31_checkbox, "31 [2G]", Position::FollowCursor)
-                ]
-                .align_items(Alignment::Center),
-                tooltip(bit30_checkbox, "30 [1G]", Position::FollowCursor),
-                tooltip(bit29_checkbox, "29 [512M]", Position::FollowCursor),
-                column![
-                    text("28").size(text_size),
-                    tooltip(bit28_checkbox, "28 [256M]", Position::FollowCursor)
-                ]
-                .align_items(Alignment::Center)
-            ]
-            .spacing(spacing)
-            .align_items(Alignment::End),
-            row![
-                column![
-                    text("27").size(text_size),
-                    tooltip(bit27_checkbox, "27 [128M]", Position::FollowCursor)
-                ]
-                .align_items(Alignment::Center),
-                tooltip(bit26_checkbox, "26 [64M]", Position::FollowCursor),
-                tooltip(bit25_checkbox, "25 [32M]", Position::FollowCursor),
-                column![
-                    text("24").size(text_size),
-                    tooltip(bit24_checkbox, "24 [16M]", Position::FollowCursor)
-                ]
-                .align_items(Alignment::Center)
-            ]
-            .spacing(spacing)
-            .align_items(Alignment::End),
-            row![
-                column![
-                    text("23").size(text_size),
-                    tooltip(bit23_checkbox, "23 [8M]", Position::FollowCursor)
-                ]
-                .align_items(Alignment::Center),
-                tooltip(bit22_checkbox, "22 [4M]", Position::FollowCursor),
-                tooltip(bit21_checkbox, "21 [2M]", Position::FollowCursor),
-                column![
-                    text("20").size(text_size),
-                    tooltip(bit20_checkbox, "20 [1M]", Position::FollowCursor)
-                ]
-                .align_items(Alignment::Center),
-            ]
-            .spacing(spacing)
-            .align_items(Alignment::End),
-            row![
-                column![
-                    text("19").size(text_size),
-                    tooltip(bit19_checkbox, "19 [512K]", Position::FollowCursor)
-                ]
-                .align_items(Alignment::Center),
-                tooltip(bit18_checkbox, "18 [256K]", Position::FollowCursor),
-                tooltip(bit17_checkbox, "17 [128K]", Position::FollowCursor),
-                column![
-                    text("16").size(text_size),
-                    tooltip(bit16_checkbox, "16 [64K]", Position::FollowCursor)
-                ]
-                .align_items(Alignment::Center),
-            ]
-            .spacing(spacing)
-            .align_items(Alignment::End),
-            row![
-                column![
-                    text("15").size(text_size),
-                    tooltip(bit15_checkbox, "15 [32K]", Position::FollowCursor)
-                ]
-                .align_items(Alignment::Center),
-                tooltip(bit14_checkbox, "14 [16K]", Position::FollowCursor),
-                tooltip(bit13_checkbox, "13 [8K]", Position::FollowCursor),
-                column![
-                    text("12").size(text_size),
-                    tooltip(bit12_checkbox, "12 [4K]", Position::FollowCursor)
-                ]
-                .align_items(Alignment::Center),
-            ]
-            .spacing(spacing)
-            .align_items(Alignment::End),
-            row![
-                column![
-                    text("11").size(text_size),
-                    tooltip(bit11_checkbox, "11 [2K]", Position::FollowCursor)
-                ]
-                .align_items(Alignment::Center),
-                tooltip(bit10_checkbox, "10 [1K]", Position::FollowCursor),
-                tooltip(bit9_checkbox, "9 [512]", Position::FollowCursor),
-                column![
-                    text("8").size(text_size),
-                    tooltip(bit8_checkbox, "8 [256]", Position::FollowCursor)
-                ]
-                .align_items(Alignment::Center),
-            ]
-            .spacing(spacing)
-            .align_items(Alignment::End),
-            row![
-                column![
-                    text("7").size(text_size),
-                    tooltip(bit7_checkbox, "7 [128]", Position::FollowCursor)
-                ]
-                .align_items(Alignment::Center),
-                tooltip(bit6_checkbox, "6 [64]", Position::FollowCursor),
-                tooltip(bit5_checkbox, "5 [32]", Position::FollowCursor),
-                column![
-                    text("4").size(text_size),
-                    tooltip(bit4_checkbox, "4 [16]", Position::FollowCursor)
-                ]
-                .align_items(Alignment::Center),
-            ]
-            .spacing(spacing)
-            .align_items(Alignment::End),
-            row![
-                column![
-                    text("3").size(text_size),
-                    tooltip(bit3_checkbox, "3 [8]", Position::FollowCursor)
-                ]
-                .align_items(Alignment::Center),
-                tooltip(bit2_checkbox, "2 [4]", Position::FollowCursor),
-                tooltip(bit1_checkbox, "1 [2]", Position::FollowCursor),
-                column![
-                    text("0").size(text_size),
-                    tooltip(bit0_checkbox, "0 [1]", Position::FollowCursor)
-                ]
-                .align_items(Alignment::Center),
-            ]
-            .spacing(spacing)
-            .align_items(Alignment::End)
-        ]
-        .spacing(group_spacing)
-        .height(height)
-        .width(Length::Fill)
+        // A group of four bits, labeled with the numbers of its first and last bit.
+        let nibble = |first: u8| {
+            (0..4)
+                .rev()
+                .fold(Row::new(), |nibble, offset| {
+                    let bit = first + offset;
+                    let bit_checkbox =
+                        checkbox("", get_bit(value, bit), BIT_MESSAGES[usize::from(bit)])
+                            .spacing(0)
+                            .style(bit_style(highlight, bit));
+                    let bit_checkbox = tooltip(
+                        bit_checkbox,
+                        bit_tooltip(bit.into()),
+                        Position::FollowCursor,
+                    );
+                    if offset == 0 || offset == 3 {
+                        nibble.push(
+                            column![text(bit.to_string()).size(text_size), bit_checkbox]
+                                .align_items(Alignment::Center),
+                        )
+                    } else {
+                        nibble.push(bit_checkbox)
+                    }
+                })
+                .spacing(spacing)
+                .align_items(Alignment::End)
+        };
+        let nibbles = |first: u8, count: u8| {
+            (0..count)
+                .rev()
+                .fold(Row::new(), |row, index| row.push(nibble(first + 4 * index)))
+                .spacing(group_spacing)
+                .height(height)
+        };
+        if wrap {
+            Row::new().push(column![nibbles(16, 4), nibbles(0, 4)].spacing(spacing))
+        } else {
+            nibbles(0, 8).width(Length::Fill)
+        }
     }
 }
 
@@ -376,14 +308,18 @@ where
     Renderer: iced_native::Renderer + iced_native::text::Renderer,
     Renderer::Theme: text::StyleSheet
         + iced_native::widget::container::StyleSheet
-        + iced_native::widget::checkbox::StyleSheet,
+        + iced_native::widget::checkbox::StyleSheet<Style = theme::Checkbox>,
 {
     fn width(&self) -> Length {
         self.width
     }
 
     fn height(&self) -> Length {
-        self.height
+        if self.wrap {
+            Length::Shrink
+        } else {
+            self.height
+        }
     }
 
     fn layout(
@@ -405,7 +341,7 @@ where
         viewport: &iced::Rectangle,
     ) {
         self.content.draw(
-            state.children.get(0).unwrap(),
+            state.children.first().unwrap(),
             renderer,
             theme,
             style,
@@ -613,7 +549,7 @@ where
     Renderer: 'a + iced_native::Renderer + iced_native::text::Renderer,
     Renderer::Theme: text::StyleSheet
         + iced_native::widget::container::StyleSheet
-        + iced_native::widget::checkbox::StyleSheet,
+        + iced_native::widget::checkbox::StyleSheet<Style = theme::Checkbox>,
     Message: Clone + 'a,
 {
     fn from(value: BinaryFieldWidget<'a, Message, Renderer>) -> Self {
@@ -637,3 +573,25 @@ fn put_bit(value: u32, bit: &bool, offset: u8) -> u32 {
     }
     value
 }
+
+/// The tooltip of a bit with its number and value, e.g. `Bit 29 [512M]`.
+fn bit_tooltip(bit: u32) -> String {
+    let value = match bit {
+        30.. => format!("{}G", 1 << (bit - 30)),
+        20.. => format!("{}M", 1 << (bit - 20)),
+        10.. => format!("{}K", 1 << (bit - 10)),
+        _ => (1 << bit).to_string(),
+    };
+    tr_args(
+        "bit_tooltip",
+        &[("bit", bit.into()), ("value", value.into())],
+    )
+}
+
+fn bit_style(highlight: u32, offset: u8) -> theme::Checkbox {
+    if get_bit(highlight, offset) {
+        theme::Checkbox::Success
+    } else {
+        theme::Checkbox::Primary
+    }
+}
@@ -0,0 +1,392 @@
+use std::io::Write;
+use std::num::NonZeroUsize;
+
+use clap::{Parser, ValueEnum};
+
+use crate::config::Config;
+use crate::language_type::LanguageType;
+use crate::messages::Message;
+use crate::settings::SettingsMessage;
+use crate::theme_type::ThemeType;
+use crate::Pages;
+use binary_calculator::copy_format::CopyFormat;
+use binary_calculator::paste_parser::parse_pasted;
+use binary_calculator::value::{Value, MAX_WIDTH};
+
+/// The exit code for a value or format which cannot be read.
+const USAGE_ERROR: i32 = 2;
+
+/// Converts a value between binary, octal, decimal and hexadecimal. Without
+/// a value the calculator window opens, set up by `--value`, `--width`,
+/// `--signed`, `--theme`, `--language` and `--page`.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub(crate) struct Args {
+    /// The value to convert, in any layout the calculator can paste, e.g.
+    /// `0x1F`, `-3`, `64Ki` or `{ 0x12, 0x34 }`.
+    #[arg(allow_negative_numbers = true)]
+    pub value: Option<String>,
+    /// The bases to print, e.g. `bin,dec`. Prints all bases if neither
+    /// `--to` nor `--format` is given.
+    #[arg(long, value_delimiter = ',')]
+    pub to: Vec<Base>,
+    /// The copy formats to print, e.g. `intel-array`, including the names
+    /// of the user templates. They always write all 32 bits, so they
+    /// cannot be combined with `--width`.
+    #[arg(long, value_delimiter = ',', conflicts_with = "width")]
+    pub format: Vec<String>,
+    /// The value the window or terminal UI starts with.
+    #[arg(
+        long = "value",
+        value_name = "VALUE",
+        allow_hyphen_values = true,
+        conflicts_with_all = ["value", "repl", "pipe"]
+    )]
+    pub start_value: Option<String>,
+    /// The width of the word in bits. The window keeps the bits above it
+    /// clear and reads the word as signed number of this width.
+    #[arg(long, default_value_t = MAX_WIDTH, value_parser = clap::value_parser!(u32).range(1..=MAX_WIDTH as i64))]
+    pub width: u32,
+    /// Reads the word as two's complement signed number.
+    #[arg(long)]
+    pub signed: bool,
+    /// Starts an interactive session with the value, e.g. `<< 4` or
+    /// `fmt motorola-array`.
+    #[arg(long, conflicts_with_all = ["to", "format"])]
+    pub repl: bool,
+    /// Shows the calculator full-screen in the terminal instead of a window.
+    #[arg(long, conflicts_with_all = ["value", "to", "format", "repl"])]
+    pub tui: bool,
+    /// Converts the values read from stdin, one per line, and writes CSV
+    /// if more than one conversion is asked for.
+    #[arg(long, conflicts_with_all = ["value", "repl", "tui"])]
+    pub pipe: bool,
+    /// The theme of the window: `light`, `dark` or the name of a custom theme.
+    #[arg(long)]
+    pub theme: Option<String>,
+    /// The language of the window, e.g. `de`.
+    #[arg(long)]
+    pub language: Option<String>,
+    /// The page the window starts with.
+    #[arg(long, value_enum)]
+    pub page: Option<Pages>,
+    /// Reads the value from this comma separated column of each line, e.g.
+    /// of a logic analyser export, counted from 1. Fields may be quoted.
+    #[arg(long, requires = "pipe")]
+    pub column: Option<NonZeroUsize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Base {
+    Bin,
+    Oct,
+    Dec,
+    Hex,
+}
+
+impl Base {
+    const ALL: [Base; 4] = [Base::Bin, Base::Oct, Base::Dec, Base::Hex];
+
+    fn radix(&self) -> u32 {
+        match self {
+            Base::Bin => 2,
+            Base::Oct => 8,
+            Base::Dec => 10,
+            Base::Hex => 16,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Base::Bin => "bin",
+            Base::Oct => "oct",
+            Base::Dec => "dec",
+            Base::Hex => "hex",
+        }
+    }
+}
+
+/// Reads the value given on the command line, masked to the width.
+pub(crate) fn initial_value(args: &Args) -> Result<Value, i32> {
+    read_value(args.value.as_deref(), args)
+}
+
+fn read_value(value: Option<&str>, args: &Args) -> Result<Value, i32> {
+    let bits = match value {
+        Some(value) => match parse_pasted(value).first() {
+            Some(interpretation) => interpretation.value,
+            None => {
+                eprintln!("cannot read `{value}` as a number");
+                return Err(USAGE_ERROR);
+            }
+        },
+        None => 0,
+    };
+    Ok(Value::new(bits, args.width, args.signed))
+}
+
+/// The messages which set the calculator up as given on the command line,
+/// sent after the saved settings are applied. `custom_themes` are the names
+/// of the saved custom themes.
+pub(crate) fn startup_messages(args: &Args, custom_themes: &[String]) -> Result<Vec<Message>, i32> {
+    let mut messages = Vec::new();
+    if args.width != MAX_WIDTH {
+        messages.push(Message::WidthChanged(args.width));
+    }
+    if let Some(value) = &args.start_value {
+        messages.push(Message::InputU32Changed(
+            read_value(Some(value), args)?.bits(),
+        ));
+    }
+    if args.signed {
+        messages.push(Message::SignToggled(true));
+    }
+    match args.theme.as_deref() {
+        None => {}
+        Some("light") => messages.push(Message::SettingsMessage(SettingsMessage::ThemeChanged(
+            ThemeType::Light,
+        ))),
+        Some("dark") => messages.push(Message::SettingsMessage(SettingsMessage::ThemeChanged(
+            ThemeType::Dark,
+        ))),
+        Some(name) if custom_themes.iter().any(|theme| theme == name) => {
+            messages.push(Message::SettingsMessage(
+                SettingsMessage::CustomThemeSelected(name.to_string()),
+            ));
+            messages.push(Message::SettingsMessage(SettingsMessage::ThemeChanged(
+                ThemeType::Custom,
+            )));
+        }
+        Some(name) => {
+            let themes: Vec<&str> = ["light", "dark"]
+                .into_iter()
+                .chain(custom_themes.iter().map(String::as_str))
+                .collect();
+            eprintln!(
+                "unknown theme `{name}`, expected one of: {}",
+                themes.join(", ")
+            );
+            return Err(USAGE_ERROR);
+        }
+    }
+    if let Some(tag) = &args.language {
+        let languages = LanguageType::all();
+        match LanguageType::try_from(tag.clone()) {
+            Ok(language) if languages.contains(&language) => messages.push(
+                Message::SettingsMessage(SettingsMessage::Language(language)),
+            ),
+            _ => {
+                let tags: Vec<String> = languages.into_iter().map(String::from).collect();
+                eprintln!(
+                    "unknown language `{tag}`, expected one of: {}",
+                    tags.join(", ")
+                );
+                return Err(USAGE_ERROR);
+            }
+        }
+    }
+    if let Some(page) = args.page {
+        messages.push(page.message());
+    }
+    Ok(messages)
+}
+
+/// A conversion to print: a base or a copy format.
+enum Conversion {
+    Base(Base),
+    Format(CopyFormat),
+}
+
+impl Conversion {
+    fn convert(&self, word: Value) -> String {
+        match self {
+            Conversion::Base(base) => word.to_string_radix(base.radix()),
+            Conversion::Format(format) => format.format(word.bits()),
+        }
+    }
+}
+
+/// The named conversions asked for with `--to` and `--format`, all bases if
+/// there are none.
+fn conversions(args: &Args) -> Result<Vec<(String, Conversion)>, i32> {
+    let templates = Config::load_read_only().copy_templates;
+    let bases = if args.to.is_empty() && args.format.is_empty() {
+        &Base::ALL[..]
+    } else {
+        &args.to[..]
+    };
+    let mut conversions: Vec<(String, Conversion)> = bases
+        .iter()
+        .map(|base| (base.name().to_string(), Conversion::Base(*base)))
+        .collect();
+    for name in &args.format {
+        let Some(format) = CopyFormat::from_name(name, &templates) else {
+            eprintln!("unknown format `{name}`, expected one of:");
+            for radix in [16, 8, 2] {
+                for format in CopyFormat::all(radix, &templates) {
+                    eprintln!("  {}", format.name());
+                }
+            }
+            return Err(USAGE_ERROR);
+        };
+        conversions.push((name.clone(), Conversion::Format(format)));
+    }
+    Ok(conversions)
+}
+
+/// Prints the conversions of the value and returns the exit code.
+pub(crate) fn run(args: &Args) -> i32 {
+    let (word, conversions) =
+        match initial_value(args).and_then(|word| Ok((word, conversions(args)?))) {
+            Ok(result) => result,
+            Err(code) => return code,
+        };
+    if let [(_, conversion)] = &conversions[..] {
+        println!("{}", conversion.convert(word));
+    } else {
+        let width = conversions
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or(0);
+        for (name, conversion) in &conversions {
+            println!("{name:>width$}: {}", conversion.convert(word));
+        }
+    }
+    0
+}
+
+/// Converts the values read from stdin, one per line, and writes one line
+/// per value: the conversion, or CSV with a header if there are several.
+/// Lines which cannot be read give an empty line and are reported on
+/// stderr; the exit code is 1 if there were any.
+pub(crate) fn pipe(args: &Args) -> i32 {
+    let conversions = match conversions(args) {
+        Ok(conversions) => conversions,
+        Err(code) => return code,
+    };
+    let csv = conversions.len() > 1;
+    let mut output = std::io::stdout().lock();
+    if csv {
+        let header: Vec<String> = conversions
+            .iter()
+            .map(|(name, _)| csv_field(name))
+            .collect();
+        if writeln!(output, "{}", header.join(",")).is_err() {
+            return 1;
+        }
+    }
+    let mut code = 0;
+    for (number, line) in std::io::stdin().lines().enumerate() {
+        let line = match line {
+            Ok(line) => line,
+            Err(err) => {
+                eprintln!("{err}");
+                return 1;
+            }
+        };
+        let input = match args.column {
+            Some(column) => csv_fields(&line)
+                .into_iter()
+                .nth(column.get() - 1)
+                .unwrap_or_default(),
+            None => line,
+        };
+        let fields: Vec<String> = match parse_pasted(&input).first() {
+            Some(interpretation) => {
+                let word = Value::new(interpretation.value, args.width, args.signed);
+                conversions
+                    .iter()
+                    .map(|(_, conversion)| conversion.convert(word))
+                    .collect()
+            }
+            None => {
+                eprintln!(
+                    "line {}: cannot read `{}` as a number",
+                    number + 1,
+                    input.trim()
+                );
+                code = 1;
+                vec![String::new(); conversions.len()]
+            }
+        };
+        let line = if csv {
+            fields
+                .iter()
+                .map(|field| csv_field(field))
+                .collect::<Vec<_>>()
+                .join(",")
+        } else {
+            fields.concat()
+        };
+        // Stop quietly when the reader is gone, e.g. `| head`.
+        if writeln!(output, "{line}").is_err() {
+            break;
+        }
+    }
+    code
+}
+
+/// Quotes a CSV field if it contains a separator or quote, e.g. a byte array.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Splits a CSV line into its fields. Quoted fields may contain separators
+/// and doubled quotes, e.g. `"0x12, 0x34"`.
+fn csv_fields(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_conflicts_with_width() {
+        let parse = |args: &[&str]| {
+            Args::try_parse_from(["binary_calculator"].iter().chain(args))
+                .map_err(|error| error.kind())
+        };
+        assert!(parse(&["0xff", "--format", "c"]).is_ok());
+        assert!(parse(&["0xff", "--width", "8", "--to", "hex"]).is_ok());
+        assert!(parse(&["--pipe", "--width", "16", "--to", "hex"]).is_ok());
+        assert_eq!(
+            parse(&["0xff", "--width", "8", "--format", "c"]).unwrap_err(),
+            clap::error::ErrorKind::ArgumentConflict
+        );
+        assert_eq!(
+            parse(&["--pipe", "--width", "16", "--format", "c"]).unwrap_err(),
+            clap::error::ErrorKind::ArgumentConflict
+        );
+    }
+
+    #[test]
+    fn csv_fields_keep_quoted_separators() {
+        assert_eq!(csv_fields("1,0x12,x"), ["1", "0x12", "x"]);
+        assert_eq!(
+            csv_fields(r#"0.5,"{0x12, 0x34}","say ""hi""",,"#),
+            ["0.5", "{0x12, 0x34}", r#"say "hi""#, "", ""]
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use cli_clipboard::{ClipboardContext, ClipboardProvider};
+
+use crate::translations::tr_args;
+
+/// Why the clipboard could not be used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ClipboardError {
+    /// There is no clipboard, e.g. on a headless X session.
+    Unavailable(String),
+    Read(String),
+    Write(String),
+}
+
+impl std::fmt::Display for ClipboardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (key, reason) = match self {
+            ClipboardError::Unavailable(reason) => ("no_clipboard", reason),
+            ClipboardError::Read(reason) => ("clipboard_read_failed", reason),
+            ClipboardError::Write(reason) => ("clipboard_write_failed", reason),
+        };
+        write!(f, "{}", tr_args(key, &[("reason", reason.as_str().into())]))
+    }
+}
+
+impl std::error::Error for ClipboardError {}
+
+/// Writes the text to the system clipboard.
+pub(crate) fn copy(contents: String) -> Result<(), ClipboardError> {
+    context()?
+        .set_contents(contents)
+        .map_err(|error| ClipboardError::Write(error.to_string()))
+}
+
+/// Reads the text of the system clipboard.
+pub(crate) fn paste() -> Result<String, ClipboardError> {
+    context()?
+        .get_contents()
+        .map_err(|error| ClipboardError::Read(error.to_string()))
+}
+
+fn context() -> Result<ClipboardContext, ClipboardError> {
+    ClipboardContext::new().map_err(|error| ClipboardError::Unavailable(error.to_string()))
+}
@@ -0,0 +1,119 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CodeLanguages {
+    #[default]
+    C,
+    Rust,
+    Python,
+    Verilog,
+}
+
+impl CodeLanguages {
+    pub const ALL: [CodeLanguages; 4] = [
+        CodeLanguages::C,
+        CodeLanguages::Rust,
+        CodeLanguages::Python,
+        CodeLanguages::Verilog,
+    ];
+
+    /// Builds the expression of a mask from its runs of set bits, e.g.
+    /// `(1u << 3) | (0x7u << 8)` in C.
+    pub fn mask_expression(&self, mask: u32) -> String {
+        let runs = bit_runs(mask);
+        if runs.is_empty() {
+            return self.literal(0);
+        }
+        runs.iter()
+            .map(|(shift, width)| {
+                let bits = self.literal(((1_u64 << width) - 1) as u32);
+                if *shift == 0 {
+                    bits
+                } else {
+                    format!("({bits} << {shift})")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+
+    /// Declares a mask constant with the given name, and its shift if the
+    /// mask is a single contiguous field.
+    pub fn mask_declaration(&self, name: &str, mask: u32) -> String {
+        // `CTRL_MASK` declares `CTRL_MASK` and `CTRL_SHIFT`.
+        let name = identifier(name);
+        let name = name.strip_suffix("_MASK").unwrap_or(&name);
+        let expression = self.mask_expression(mask);
+        let mut declaration = match self {
+            CodeLanguages::C => format!("#define {name}_MASK ({expression})"),
+            CodeLanguages::Rust => format!("pub const {name}_MASK: u32 = {expression};"),
+            CodeLanguages::Python => format!("{name}_MASK = {expression}"),
+            CodeLanguages::Verilog => format!("localparam [31:0] {name}_MASK = {expression};"),
+        };
+        if let [(shift, _)] = bit_runs(mask)[..] {
+            declaration.push('\n');
+            declaration.push_str(&match self {
+                CodeLanguages::C => format!("#define {name}_SHIFT {shift}u"),
+                CodeLanguages::Rust => format!("pub const {name}_SHIFT: u32 = {shift};"),
+                CodeLanguages::Python => format!("{name}_SHIFT = {shift}"),
+                CodeLanguages::Verilog => format!("localparam {name}_SHIFT = {shift};"),
+            });
+        }
+        declaration
+    }
+
+    fn literal(&self, value: u32) -> String {
+        match self {
+            CodeLanguages::C if value < 2 => format!("{value}u"),
+            CodeLanguages::C => format!("{value:#x}u"),
+            CodeLanguages::Verilog => format!("32'h{value:x}"),
+            _ if value < 2 => value.to_string(),
+            _ => format!("{value:#x}"),
+        }
+    }
+}
+
+impl std::fmt::Display for CodeLanguages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                CodeLanguages::C => "C",
+                CodeLanguages::Rust => "Rust",
+                CodeLanguages::Python => "Python",
+                CodeLanguages::Verilog => "Verilog",
+            }
+        )
+    }
+}
+
+/// Splits a mask into its runs of set bits as `(shift, width)`, lowest first.
+pub fn bit_runs(mask: u32) -> Vec<(u32, u32)> {
+    let mut runs = Vec::new();
+    let mut rest = mask as u64;
+    while rest != 0 {
+        let shift = rest.trailing_zeros();
+        let width = (rest >> shift).trailing_ones();
+        runs.push((shift, width));
+        rest &= !(((1_u64 << width) - 1) << shift);
+    }
+    runs
+}
+
+/// Turns a field name into an upper case identifier, e.g. `tx enable` into `TX_ENABLE`.
+pub fn identifier(name: &str) -> String {
+    let mut identifier: String = name
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if identifier.is_empty() || identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        identifier.insert(0, '_');
+    }
+    identifier
+}
@@ -0,0 +1,137 @@
+use iced::widget::{column, container, pick_list, row, slider, text, text_input, Column};
+use iced::{theme, Alignment, Color, Element, Length, Theme};
+use iced_style::container::{Appearance, StyleSheet};
+
+use crate::translations::tr;
+use binary_calculator::color_formats::{parse_html_color, Channel, ColorFormats};
+
+pub(crate) struct ColorPanel {
+    format: ColorFormats,
+    /// The `#RRGGBB` colour being typed.
+    input: String,
+    /// Whether the last submitted colour could not be parsed.
+    invalid: bool,
+}
+
+impl<'a> ColorPanel {
+    pub fn new() -> Self {
+        Self {
+            format: ColorFormats::default(),
+            input: String::new(),
+            invalid: false,
+        }
+    }
+
+    pub fn update(&mut self, msg: ColorMessage, value: &mut u32) {
+        match msg {
+            ColorMessage::FormatChanged(format) => self.format = format,
+            ColorMessage::ChannelChanged(channel, raw) => {
+                if let Some((_, shift, bits)) = self
+                    .format
+                    .channels()
+                    .iter()
+                    .find(|(c, _, _)| *c == channel)
+                {
+                    let mask = ((1 << bits) - 1) << shift;
+                    *value = (*value & !mask) | ((raw << shift) & mask);
+                }
+            }
+            ColorMessage::HexChanged(input) => {
+                self.input = input;
+                self.invalid = false;
+            }
+            ColorMessage::HexSubmitted => match parse_html_color(&self.input) {
+                Some(rgba) => {
+                    *value = (*value & !self.format.mask()) | self.format.pack(rgba);
+                    self.input.clear();
+                }
+                None => self.invalid = true,
+            },
+        }
+    }
+
+    pub fn view(&self, value: u32) -> Element<'a, ColorMessage> {
+        let rgba = self.format.unpack(value);
+        let format_pick_list = pick_list(
+            &ColorFormats::ALL[..],
+            Some(self.format),
+            ColorMessage::FormatChanged,
+        )
+        .width(Length::Units(150));
+        let swatch = container(text(""))
+            .width(Length::Units(100))
+            .height(Length::Units(100))
+            .style(theme::Container::Custom(Box::new(Swatch(
+                Color::from_rgba8(rgba[0], rgba[1], rgba[2], rgba[3] as f32 / 255.0),
+            ))));
+        let channels = self.format.channels().iter().fold(
+            Column::new().spacing(10),
+            |column, (channel, shift, bits)| {
+                let channel = *channel;
+                let max = (1 << bits) - 1;
+                let raw = (value >> shift) & max;
+                column.push(
+                    row![
+                        text(channel).width(Length::Units(20)),
+                        slider(0..=max, raw, move |raw| ColorMessage::ChannelChanged(
+                            channel, raw
+                        ))
+                        .width(Length::Units(256)),
+                        text(format!("{} ({})", raw, rgba[channel.index()])),
+                    ]
+                    .spacing(10)
+                    .align_items(Alignment::Center),
+                )
+            },
+        );
+        let hex_input = text_input(
+            &format!("#{:02x}{:02x}{:02x}", rgba[0], rgba[1], rgba[2]),
+            &self.input,
+            ColorMessage::HexChanged,
+        )
+        .on_submit(ColorMessage::HexSubmitted)
+        .width(Length::Units(150));
+        let mut hex_row = row![hex_input].spacing(10).align_items(Alignment::Center);
+        if self.invalid {
+            hex_row = hex_row.push(text(tr("invalid_colour")));
+        }
+        row![
+            column![format_pick_list, swatch].spacing(10),
+            column![
+                text(format!(
+                    "#{:02x}{:02x}{:02x}{:02x}",
+                    rgba[0], rgba[1], rgba[2], rgba[3]
+                )),
+                channels,
+                hex_row
+            ]
+            .spacing(10),
+        ]
+        .spacing(20)
+        .into()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum ColorMessage {
+    FormatChanged(ColorFormats),
+    ChannelChanged(Channel, u32),
+    HexChanged(String),
+    HexSubmitted,
+}
+
+/// The container style of the colour swatch.
+pub(crate) struct Swatch(pub Color);
+
+impl StyleSheet for Swatch {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> Appearance {
+        Appearance {
+            background: Some(self.0.into()),
+            border_width: 1.0,
+            border_color: style.palette().text,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,184 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorFormats {
+    Rgb332,
+    #[default]
+    Rgb565,
+    Argb1555,
+    Rgb888,
+    Argb8888,
+    Rgba8888,
+}
+
+/// A colour channel of a packed pixel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Channel {
+    Red,
+    Green,
+    Blue,
+    Alpha,
+}
+
+impl ColorFormats {
+    pub const ALL: [ColorFormats; 6] = [
+        ColorFormats::Rgb332,
+        ColorFormats::Rgb565,
+        ColorFormats::Argb1555,
+        ColorFormats::Rgb888,
+        ColorFormats::Argb8888,
+        ColorFormats::Rgba8888,
+    ];
+
+    /// The channels of the format as `(channel, shift, bits)`, most significant first.
+    pub fn channels(&self) -> &'static [(Channel, u32, u32)] {
+        match self {
+            ColorFormats::Rgb332 => &[
+                (Channel::Red, 5, 3),
+                (Channel::Green, 2, 3),
+                (Channel::Blue, 0, 2),
+            ],
+            ColorFormats::Rgb565 => &[
+                (Channel::Red, 11, 5),
+                (Channel::Green, 5, 6),
+                (Channel::Blue, 0, 5),
+            ],
+            ColorFormats::Argb1555 => &[
+                (Channel::Alpha, 15, 1),
+                (Channel::Red, 10, 5),
+                (Channel::Green, 5, 5),
+                (Channel::Blue, 0, 5),
+            ],
+            ColorFormats::Rgb888 => &[
+                (Channel::Red, 16, 8),
+                (Channel::Green, 8, 8),
+                (Channel::Blue, 0, 8),
+            ],
+            ColorFormats::Argb8888 => &[
+                (Channel::Alpha, 24, 8),
+                (Channel::Red, 16, 8),
+                (Channel::Green, 8, 8),
+                (Channel::Blue, 0, 8),
+            ],
+            ColorFormats::Rgba8888 => &[
+                (Channel::Red, 24, 8),
+                (Channel::Green, 16, 8),
+                (Channel::Blue, 8, 8),
+                (Channel::Alpha, 0, 8),
+            ],
+        }
+    }
+
+    /// The bits of a value which are used by the format.
+    pub fn mask(&self) -> u32 {
+        self.channels().iter().fold(0, |mask, (_, shift, bits)| {
+            mask | (channel_max(*bits) << shift)
+        })
+    }
+
+    /// Unpacks the value into 8-bit RGBA. Formats without alpha are opaque.
+    pub fn unpack(&self, value: u32) -> [u8; 4] {
+        let mut rgba = [0, 0, 0, 255];
+        for (channel, shift, bits) in self.channels() {
+            let max = channel_max(*bits);
+            let raw = (value >> shift) & max;
+            rgba[channel.index()] = ((raw * 255 + max / 2) / max) as u8;
+        }
+        rgba
+    }
+
+    /// Packs 8-bit RGBA into the bits of the format, rounding to the channel widths.
+    pub fn pack(&self, rgba: [u8; 4]) -> u32 {
+        self.channels()
+            .iter()
+            .fold(0, |value, (channel, shift, bits)| {
+                let max = channel_max(*bits);
+                let raw = (rgba[channel.index()] as u32 * max + 127) / 255;
+                value | (raw << shift)
+            })
+    }
+}
+
+impl Channel {
+    /// The index of the channel in an RGBA array.
+    pub fn index(&self) -> usize {
+        match self {
+            Channel::Red => 0,
+            Channel::Green => 1,
+            Channel::Blue => 2,
+            Channel::Alpha => 3,
+        }
+    }
+}
+
+impl std::fmt::Display for ColorFormats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ColorFormats::Rgb332 => "RGB332",
+                ColorFormats::Rgb565 => "RGB565",
+                ColorFormats::Argb1555 => "ARGB1555",
+                ColorFormats::Rgb888 => "RGB888",
+                ColorFormats::Argb8888 => "ARGB8888",
+                ColorFormats::Rgba8888 => "RGBA8888",
+            }
+        )
+    }
+}
+
+impl std::fmt::Display for Channel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Channel::Red => "R",
+                Channel::Green => "G",
+                Channel::Blue => "B",
+                Channel::Alpha => "A",
+            }
+        )
+    }
+}
+
+fn channel_max(bits: u32) -> u32 {
+    (1 << bits) - 1
+}
+
+/// Parses a colour in `#RGB`, `#RRGGBB` or `#RRGGBBAA` notation into 8-bit RGBA.
+pub fn parse_html_color(input: &str) -> Option<[u8; 4]> {
+    let digits = input.trim().trim_start_matches('#');
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let byte = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).ok();
+    match digits.len() {
+        3 => {
+            let mut rgba = [0, 0, 0, 255];
+            for (i, c) in digits.chars().enumerate() {
+                rgba[i] = c.to_digit(16)? as u8 * 0x11;
+            }
+            Some(rgba)
+        }
+        6 => Some([byte(0)?, byte(2)?, byte(4)?, 255]),
+        8 => Some([byte(0)?, byte(2)?, byte(4)?, byte(6)?]),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html_colors() {
+        assert_eq!(parse_html_color("#1e90ff"), Some([0x1e, 0x90, 0xff, 255]));
+        assert_eq!(parse_html_color(" f0a "), Some([0xff, 0x00, 0xaa, 255]));
+        assert_eq!(
+            parse_html_color("#1e90ff80"),
+            Some([0x1e, 0x90, 0xff, 0x80])
+        );
+        assert_eq!(parse_html_color("#ff00"), None);
+        assert_eq!(parse_html_color("#gggggg"), None);
+    }
+}
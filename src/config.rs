@@ -0,0 +1,193 @@
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+use serde::{Deserialize, Serialize};
+
+use crate::custom_theme::CustomTheme;
+use crate::language_type::LanguageType;
+use crate::theme_type::ThemeType;
+use crate::translations::tr_args;
+use binary_calculator::format_template::FormatTemplate;
+use binary_calculator::value::MAX_WIDTH;
+
+/// The settings saved between two runs in
+/// `$XDG_CONFIG_HOME/binary_calculator/settings.toml`.
+///
+/// Missing entries, e.g. of a file written by an older version, take their
+/// default values. The entries which are tables come last, as TOML
+/// requires.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct Config {
+    /// The version of the file layout.
+    pub version: u32,
+    pub theme: ThemeType,
+    /// The name of the custom theme in use.
+    pub custom_theme: Option<String>,
+    /// The language of the user interface, none to detect the one of the system.
+    pub language: Option<LanguageType>,
+    /// The name of the number locale, none to follow the language.
+    pub number_locale: Option<String>,
+    pub signed: bool,
+    /// The number of bits of the value, 1 to `MAX_WIDTH`.
+    pub width: u32,
+    pub value: u32,
+    /// The zoom of the user interface in percent.
+    pub scale: u16,
+    /// The labels of the copy formats used last.
+    pub copy_formats: Vec<String>,
+    pub copy_templates: Vec<FormatTemplate>,
+    /// The palettes for `ThemeType::Custom`.
+    pub custom_themes: Vec<CustomTheme>,
+    pub window: WindowGeometry,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct WindowGeometry {
+    pub width: u32,
+    pub height: u32,
+    /// The position, none to let the system place the window.
+    pub x: Option<i32>,
+    pub y: Option<i32>,
+}
+
+/// Why the settings could not be read or written.
+#[derive(Debug)]
+pub(crate) enum ConfigError {
+    Io(PathBuf, io::Error),
+    /// The file is no valid settings file. It was moved to the given backup.
+    Corrupt(PathBuf, String),
+    Serialize(String),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io(path, error) => write!(f, "{}", file_error(path, error)),
+            ConfigError::Corrupt(backup, error) => write!(
+                f,
+                "{}",
+                tr_args(
+                    "settings_invalid",
+                    &[
+                        ("backup", backup.display().to_string().into()),
+                        ("reason", error.as_str().into())
+                    ]
+                )
+            ),
+            ConfigError::Serialize(error) => write!(
+                f,
+                "{}",
+                tr_args(
+                    "settings_write_failed",
+                    &[("reason", error.as_str().into())]
+                )
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Describes an error of reading or writing a file.
+pub(crate) fn file_error(path: &Path, error: &io::Error) -> String {
+    tr_args(
+        "file_error",
+        &[
+            ("path", path.display().to_string().into()),
+            ("reason", error.to_string().into()),
+        ],
+    )
+}
+
+impl Config {
+    pub const VERSION: u32 = 1;
+
+    /// The path of the settings file, if there is a config directory.
+    pub fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("binary_calculator").join("settings.toml"))
+    }
+
+    /// Reads the settings file. A missing file gives the default settings, a
+    /// corrupt one is moved aside so it is not overwritten by the next save.
+    pub fn load() -> Result<Config, ConfigError> {
+        let Some(path) = Self::path() else {
+            return Ok(Config::default());
+        };
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(error) => return Err(ConfigError::Io(path, error)),
+        };
+        toml::from_str(&contents)
+            .map(Config::clamped)
+            .map_err(|error| {
+                let backup = path.with_extension("toml.bak");
+                match fs::rename(&path, &backup) {
+                    Ok(()) => ConfigError::Corrupt(backup, error.to_string()),
+                    Err(rename_error) => ConfigError::Io(path, rename_error),
+                }
+            })
+    }
+
+    /// Reads the settings file without ever moving or writing it, for the
+    /// command line modes. A missing or corrupt file gives the defaults.
+    pub fn load_read_only() -> Config {
+        Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| toml::from_str(&contents).ok())
+            .map(Config::clamped)
+            .unwrap_or_default()
+    }
+
+    /// Brings entries edited by hand back into their range.
+    fn clamped(mut self) -> Self {
+        self.width = self.width.clamp(1, MAX_WIDTH);
+        self
+    }
+
+    /// Writes the settings file, creating its directory if needed.
+    pub fn save(&self) -> Result<(), ConfigError> {
+        let Some(path) = Self::path() else {
+            return Ok(());
+        };
+        let contents =
+            toml::to_string(self).map_err(|error| ConfigError::Serialize(error.to_string()))?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|error| ConfigError::Io(dir.to_path_buf(), error))?;
+        }
+        fs::write(&path, contents).map_err(|error| ConfigError::Io(path, error))
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            version: Self::VERSION,
+            theme: ThemeType::Dark,
+            custom_themes: Vec::new(),
+            custom_theme: None,
+            language: None,
+            number_locale: None,
+            signed: false,
+            width: MAX_WIDTH,
+            value: 0,
+            scale: 100,
+            copy_formats: Vec::new(),
+            copy_templates: Vec::new(),
+            window: WindowGeometry::default(),
+        }
+    }
+}
+
+impl Default for WindowGeometry {
+    fn default() -> Self {
+        Self {
+            width: 885,
+            height: 450,
+            x: None,
+            y: None,
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::bin_formats::BinFormats;
+use crate::format_template::FormatTemplate;
+use crate::hex_formats::HexFormats;
+use crate::literal_formats::LiteralFormats;
+use crate::oct_formats::OctFormats;
+
+/// An entry of a copy pick list, a built-in format, a source code literal
+/// or a template defined by the user in the settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CopyFormat {
+    Hex(HexFormats),
+    Oct(OctFormats),
+    Bin(BinFormats),
+    Literal(LiteralFormats),
+    Custom(Box<FormatTemplate>),
+}
+
+impl CopyFormat {
+    pub fn format(&self, value: u32) -> String {
+        match self {
+            CopyFormat::Hex(format) => format.template().format(value),
+            CopyFormat::Oct(format) => format.template().format(value),
+            CopyFormat::Bin(format) => format.template().format(value),
+            CopyFormat::Literal(format) => format.format(value),
+            CopyFormat::Custom(template) => template.format(value),
+        }
+    }
+
+    /// The radix of the pick list which offers the format.
+    pub fn radix(&self) -> u32 {
+        match self {
+            CopyFormat::Hex(_) | CopyFormat::Literal(_) => 16,
+            CopyFormat::Oct(_) => 8,
+            CopyFormat::Bin(_) => 2,
+            CopyFormat::Custom(template) => template.radix,
+        }
+    }
+
+    /// The name of the format on the command line, e.g. `intel-array`, or
+    /// the name of the user template.
+    pub fn name(&self) -> &str {
+        match self {
+            CopyFormat::Hex(format) => format.name(),
+            CopyFormat::Oct(format) => format.name(),
+            CopyFormat::Bin(format) => format.name(),
+            CopyFormat::Literal(format) => format.name(),
+            CopyFormat::Custom(template) => &template.name,
+        }
+    }
+
+    /// Finds the format with the given name in the pick lists of all radixes.
+    pub fn from_name(name: &str, templates: &[FormatTemplate]) -> Option<CopyFormat> {
+        [16, 8, 2]
+            .iter()
+            .flat_map(|radix| CopyFormat::all(*radix, templates))
+            .find(|format| format.name() == name)
+    }
+
+    /// The entries of the copy pick list of a radix: the built-in formats,
+    /// for hexadecimal the source code literals, and the user templates.
+    pub fn all(radix: u32, templates: &[FormatTemplate]) -> Vec<CopyFormat> {
+        let built_in: Vec<CopyFormat> = match radix {
+            2 => BinFormats::ALL
+                .iter()
+                .map(|f| CopyFormat::Bin(*f))
+                .collect(),
+            8 => OctFormats::ALL
+                .iter()
+                .map(|f| CopyFormat::Oct(*f))
+                .collect(),
+            _ => HexFormats::ALL
+                .iter()
+                .map(|f| CopyFormat::Hex(*f))
+                .chain(LiteralFormats::ALL.iter().map(|f| CopyFormat::Literal(*f)))
+                .collect(),
+        };
+        built_in
+            .into_iter()
+            .chain(
+                templates
+                    .iter()
+                    .filter(|template| template.radix == radix)
+                    .map(|template| CopyFormat::Custom(Box::new(template.clone()))),
+            )
+            .collect()
+    }
+}
+
+impl std::fmt::Display for CopyFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CopyFormat::Hex(format) => format.fmt(f),
+            CopyFormat::Oct(format) => format.fmt(f),
+            CopyFormat::Bin(format) => format.fmt(f),
+            CopyFormat::Literal(format) => format.fmt(f),
+            CopyFormat::Custom(template) => template.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+use iced::theme::Palette;
+use iced::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::config::file_error;
+use crate::translations::{tr, tr_args};
+use binary_calculator::color_formats::parse_html_color;
+
+/// A named palette for `ThemeType::Custom`, with the colours in `#RRGGBB`
+/// notation so theme files are easy to edit by hand.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct CustomTheme {
+    pub name: String,
+    pub background: String,
+    pub text: String,
+    pub primary: String,
+    pub success: String,
+    pub danger: String,
+}
+
+/// The contents of a theme file, which must give the name and every colour.
+#[derive(Deserialize)]
+struct ThemeFile {
+    name: String,
+    background: String,
+    text: String,
+    primary: String,
+    success: String,
+    danger: String,
+}
+
+/// One colour of a [`CustomTheme`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PaletteEntry {
+    Background,
+    Text,
+    Primary,
+    Success,
+    Danger,
+}
+
+/// Why a theme file could not be read or written.
+#[derive(Debug)]
+pub(crate) enum ThemeFileError {
+    Io(PathBuf, io::Error),
+    Invalid(PathBuf, String),
+}
+
+impl CustomTheme {
+    /// The colour of the entry as written by the user.
+    pub fn color(&self, entry: PaletteEntry) -> &str {
+        match entry {
+            PaletteEntry::Background => &self.background,
+            PaletteEntry::Text => &self.text,
+            PaletteEntry::Primary => &self.primary,
+            PaletteEntry::Success => &self.success,
+            PaletteEntry::Danger => &self.danger,
+        }
+    }
+
+    pub fn set_color(&mut self, entry: PaletteEntry, color: String) {
+        match entry {
+            PaletteEntry::Background => self.background = color,
+            PaletteEntry::Text => self.text = color,
+            PaletteEntry::Primary => self.primary = color,
+            PaletteEntry::Success => self.success = color,
+            PaletteEntry::Danger => self.danger = color,
+        }
+    }
+
+    /// The parsed colour of the entry, the one of the default theme if it is
+    /// no valid colour.
+    pub fn parsed_color(&self, entry: PaletteEntry) -> Color {
+        let rgba = parse_html_color(self.color(entry))
+            .or_else(|| parse_html_color(CustomTheme::default().color(entry)))
+            .unwrap_or([0, 0, 0, 255]);
+        Color::from_rgba8(rgba[0], rgba[1], rgba[2], rgba[3] as f32 / 255.0)
+    }
+
+    pub fn palette(&self) -> Palette {
+        Palette {
+            background: self.parsed_color(PaletteEntry::Background),
+            text: self.parsed_color(PaletteEntry::Text),
+            primary: self.parsed_color(PaletteEntry::Primary),
+            success: self.parsed_color(PaletteEntry::Success),
+            danger: self.parsed_color(PaletteEntry::Danger),
+        }
+    }
+
+    /// Reads a theme file written by [`CustomTheme::export`]. Every entry
+    /// is required and every colour must be valid. A theme without a name
+    /// is named after the file.
+    pub fn import(path: &Path) -> Result<Self, ThemeFileError> {
+        let contents =
+            fs::read_to_string(path).map_err(|error| ThemeFileError::Io(path.into(), error))?;
+        let file: ThemeFile = toml::from_str(&contents)
+            .map_err(|error| ThemeFileError::Invalid(path.into(), error.to_string()))?;
+        let name = match file.name.trim() {
+            "" => path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            name => name.to_string(),
+        };
+        let theme = CustomTheme {
+            name,
+            background: file.background,
+            text: file.text,
+            primary: file.primary,
+            success: file.success,
+            danger: file.danger,
+        };
+        match PaletteEntry::ALL
+            .into_iter()
+            .find(|entry| parse_html_color(theme.color(*entry)).is_none())
+        {
+            Some(entry) => Err(ThemeFileError::Invalid(
+                path.into(),
+                format!("{entry}: {}", theme.color(entry)),
+            )),
+            None => Ok(theme),
+        }
+    }
+
+    /// Writes the theme to a TOML file.
+    pub fn export(&self, path: &Path) -> Result<(), ThemeFileError> {
+        let contents = toml::to_string(self)
+            .map_err(|error| ThemeFileError::Invalid(path.into(), error.to_string()))?;
+        fs::write(path, contents).map_err(|error| ThemeFileError::Io(path.into(), error))
+    }
+}
+
+impl Default for CustomTheme {
+    fn default() -> Self {
+        Self {
+            name: "Pink".to_string(),
+            background: "#ffe6ff".to_string(),
+            text: "#000000".to_string(),
+            primary: "#808000".to_string(),
+            success: "#00ff00".to_string(),
+            danger: "#ff0000".to_string(),
+        }
+    }
+}
+
+impl PaletteEntry {
+    pub const ALL: [PaletteEntry; 5] = [
+        PaletteEntry::Background,
+        PaletteEntry::Text,
+        PaletteEntry::Primary,
+        PaletteEntry::Success,
+        PaletteEntry::Danger,
+    ];
+}
+
+impl std::fmt::Display for PaletteEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            tr(match self {
+                PaletteEntry::Background => "background",
+                PaletteEntry::Text => "text",
+                PaletteEntry::Primary => "primary",
+                PaletteEntry::Success => "success",
+                PaletteEntry::Danger => "danger",
+            })
+        )
+    }
+}
+
+impl std::fmt::Display for ThemeFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThemeFileError::Io(path, error) => write!(f, "{}", file_error(path, error)),
+            ThemeFileError::Invalid(path, error) => write!(
+                f,
+                "{}",
+                tr_args(
+                    "theme_file_invalid",
+                    &[
+                        ("path", path.display().to_string().into()),
+                        ("reason", error.as_str().into())
+                    ]
+                )
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ThemeFileError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn import_requires_every_valid_colour() {
+        let path = std::env::temp_dir().join(format!(
+            "binary_calculator-theme-{}.toml",
+            std::process::id()
+        ));
+        let import = |contents: &str| {
+            fs::write(&path, contents).unwrap();
+            let theme = CustomTheme::import(&path);
+            fs::remove_file(&path).unwrap();
+            theme
+        };
+        let valid = toml::to_string(&CustomTheme::default()).unwrap();
+
+        assert_eq!(import(&valid).unwrap(), CustomTheme::default());
+        assert!(matches!(
+            import("name = \"Empty\"\n"),
+            Err(ThemeFileError::Invalid(..))
+        ));
+        assert!(matches!(
+            import(&valid.replace("#ff0000", "#ff00")),
+            Err(ThemeFileError::Invalid(..))
+        ));
+        let unnamed = import(&valid.replace("\"Pink\"", "\"\"")).unwrap();
+        assert_eq!(unnamed.name, path.file_stem().unwrap().to_string_lossy());
+    }
+}
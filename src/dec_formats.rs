@@ -1,17 +1,57 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum DecFormats {
+use crate::magnitude;
+use num_format::{CustomFormat, Grouping, Locale, ToFormattedString};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecFormats {
+    #[default]
     Plain,
     PointSeperator,
     CommaSeperator,
+    ThinSpaceSeperator,
+    IndianGrouping,
+    LocaleSeperator,
+    Scientific,
+    Engineering,
+    SiPrefix,
+    IecPrefix,
 }
 
 impl DecFormats {
-    pub const ALL: [Self; 3] = [Self::Plain, Self::PointSeperator, Self::CommaSeperator];
-}
+    pub const ALL: [Self; 10] = [
+        Self::Plain,
+        Self::PointSeperator,
+        Self::CommaSeperator,
+        Self::ThinSpaceSeperator,
+        Self::IndianGrouping,
+        Self::LocaleSeperator,
+        Self::Scientific,
+        Self::Engineering,
+        Self::SiPrefix,
+        Self::IecPrefix,
+    ];
 
-impl Default for DecFormats {
-    fn default() -> Self {
-        Self::Plain
+    /// Formats the value, using the digit grouping of `locale` for
+    /// [`DecFormats::LocaleSeperator`].
+    pub fn format(&self, value: i64, locale: &Locale) -> String {
+        match self {
+            Self::Plain => value.to_string(),
+            Self::PointSeperator => value.to_formatted_string(&Locale::de),
+            Self::CommaSeperator => value.to_formatted_string(&Locale::en),
+            Self::ThinSpaceSeperator => {
+                let format = CustomFormat::builder()
+                    .grouping(Grouping::Standard)
+                    .separator("\u{2009}")
+                    .build()
+                    .unwrap();
+                value.to_formatted_string(&format)
+            }
+            Self::IndianGrouping => value.to_formatted_string(&Locale::en_IN),
+            Self::LocaleSeperator => value.to_formatted_string(locale),
+            Self::Scientific => magnitude::scientific(value),
+            Self::Engineering => magnitude::engineering(value),
+            Self::SiPrefix => magnitude::si(value),
+            Self::IecPrefix => magnitude::iec(value),
+        }
     }
 }
 
@@ -24,6 +64,13 @@ impl std::fmt::Display for DecFormats {
                 Self::Plain => "123456",
                 Self::CommaSeperator => "123,456",
                 Self::PointSeperator => "123.456",
+                Self::ThinSpaceSeperator => "123\u{2009}456",
+                Self::IndianGrouping => "12,34,567",
+                Self::LocaleSeperator => "Locale",
+                Self::Scientific => "1.23456e5",
+                Self::Engineering => "123.456e3",
+                Self::SiPrefix => "123.456k",
+                Self::IecPrefix => "64Ki",
             }
         )
     }
@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+
+/// The layout of a copied value: the value is split into groups of bits,
+/// each group is written with its own prefix, suffix and brackets and the
+/// groups are joined by the separator.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FormatTemplate {
+    /// The name shown in the copy pick list.
+    pub name: String,
+    /// The base of the digits, 2, 8 or 16.
+    pub radix: u32,
+    /// The number of bits per group, 4, 8, 16 or 32.
+    pub group_bits: u32,
+    /// The text between two groups, e.g. `, `.
+    pub separator: String,
+    /// The text in front of every group, e.g. `0x`.
+    pub prefix: String,
+    /// The text after every group, e.g. `u`.
+    pub suffix: String,
+    pub uppercase: bool,
+    /// Whether the bytes are written least significant first (Intel).
+    pub little_endian: bool,
+    /// The brackets around every group, e.g. `[` and `]`.
+    pub group_brackets: (String, String),
+    /// The brackets around the whole value, e.g. `[` and `]`.
+    pub brackets: (String, String),
+}
+
+impl FormatTemplate {
+    pub const GROUP_BITS: [u32; 4] = [4, 8, 16, 32];
+    pub const RADIXES: [u32; 3] = [16, 8, 2];
+
+    pub fn new(name: &str, group_bits: u32, separator: &str, prefix: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            radix: 16,
+            group_bits,
+            separator: separator.to_string(),
+            prefix: prefix.to_string(),
+            suffix: String::new(),
+            uppercase: false,
+            little_endian: false,
+            group_brackets: (String::new(), String::new()),
+            brackets: (String::new(), String::new()),
+        }
+    }
+
+    /// Writes the groups in octal.
+    #[must_use]
+    pub fn octal(mut self) -> Self {
+        self.radix = 8;
+        self
+    }
+
+    /// Writes the groups in binary.
+    #[must_use]
+    pub fn binary(mut self) -> Self {
+        self.radix = 2;
+        self
+    }
+
+    /// Writes the bytes least significant first.
+    #[must_use]
+    pub fn little_endian(mut self) -> Self {
+        self.little_endian = true;
+        self
+    }
+
+    /// Puts every group in brackets.
+    #[must_use]
+    pub fn group_brackets(mut self, open: &str, close: &str) -> Self {
+        self.group_brackets = (open.to_string(), close.to_string());
+        self
+    }
+
+    /// Puts the whole value in brackets.
+    #[must_use]
+    pub fn brackets(mut self, open: &str, close: &str) -> Self {
+        self.brackets = (open.to_string(), close.to_string());
+        self
+    }
+
+    /// Formats the value with this template.
+    pub fn format(&self, value: u32) -> String {
+        let value = if self.little_endian {
+            value.swap_bytes()
+        } else {
+            value
+        };
+        let group_bits = self.group_bits.clamp(4, 32);
+        let digits = match self.radix {
+            2 => group_bits,
+            8 => group_bits.div_ceil(3),
+            _ => group_bits / 4,
+        } as usize;
+        let groups = (0..32 / group_bits)
+            .rev()
+            .map(|index| {
+                let group =
+                    ((value as u64 >> (index * group_bits)) & ((1 << group_bits) - 1)) as u32;
+                let group = match self.radix {
+                    2 => format!("{group:0digits$b}"),
+                    8 => format!("{group:0digits$o}"),
+                    _ if self.uppercase => format!("{group:0digits$X}"),
+                    _ => format!("{group:0digits$x}"),
+                };
+                format!(
+                    "{}{}{}{}{}",
+                    self.group_brackets.0, self.prefix, group, self.suffix, self.group_brackets.1
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(&self.separator);
+        format!("{}{}{}", self.brackets.0, groups, self.brackets.1)
+    }
+}
+
+impl Default for FormatTemplate {
+    fn default() -> Self {
+        Self::new("", 8, " ", "0x")
+    }
+}
+
+impl std::fmt::Display for FormatTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.name, self.format(0x0011eeff))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups() {
+        let bytes = FormatTemplate::new("bytes", 8, " ", "0x");
+        assert_eq!(bytes.format(0x0011_eeff), "0x00 0x11 0xee 0xff");
+        assert_eq!(
+            bytes.clone().little_endian().format(0x0011_eeff),
+            "0xff 0xee 0x11 0x00"
+        );
+        let upper = FormatTemplate {
+            uppercase: true,
+            ..bytes
+        };
+        assert_eq!(upper.format(0x0011_eeff), "0x00 0x11 0xEE 0xFF");
+    }
+
+    #[test]
+    fn radixes() {
+        assert_eq!(
+            FormatTemplate::new("octal", 8, ",", "")
+                .octal()
+                .format(0x0011_eeff),
+            "000,021,356,377"
+        );
+        assert_eq!(
+            FormatTemplate::new("binary", 16, "_", "")
+                .binary()
+                .format(0x0011_eeff),
+            "0000000000010001_1110111011111111"
+        );
+    }
+
+    #[test]
+    fn brackets() {
+        let template = FormatTemplate::new("words", 16, ", ", "0x")
+            .group_brackets("(", ")")
+            .brackets("[", "]");
+        assert_eq!(template.format(0x1234_5678), "[(0x1234), (0x5678)]");
+        assert_eq!(template.to_string(), "words: [(0x0011), (0xeeff)]");
+    }
+}
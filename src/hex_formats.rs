@@ -1,5 +1,8 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum HexFormats {
+use crate::format_template::FormatTemplate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HexFormats {
+    #[default]
     MotorolaSmall1Block,
     MotorolaSmall2Blocks,
     MotorolaSmall4Blocks,
@@ -29,11 +32,52 @@ impl HexFormats {
         HexFormats::Intel4BlocksWithXWitchBrackets,
         HexFormats::IntelArray,
     ];
-}
 
-impl Default for HexFormats {
-    fn default() -> Self {
-        HexFormats::MotorolaSmall1Block
+    /// The name of the format on the command line, e.g. `intel-array`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            HexFormats::MotorolaSmall1Block => "motorola",
+            HexFormats::MotorolaSmall2Blocks => "motorola-2-blocks",
+            HexFormats::MotorolaSmall4Blocks => "motorola-4-blocks",
+            HexFormats::MotorolaSmall1BlockWithX => "motorola-0x",
+            HexFormats::MotorolaSmall2BlocksWithX => "motorola-2-blocks-0x",
+            HexFormats::MotorolaSmall4BlocksWithX => "motorola-4-blocks-0x",
+            HexFormats::MotorolaSmall4BlocksWithXWithBrackets => "motorola-4-blocks-brackets",
+            HexFormats::MotorolaArray => "motorola-array",
+            HexFormats::Intel4Blocks => "intel-4-blocks",
+            HexFormats::Intel4BlocksWithX => "intel-4-blocks-0x",
+            HexFormats::Intel4BlocksWithXWitchBrackets => "intel-4-blocks-brackets",
+            HexFormats::IntelArray => "intel-array",
+        }
+    }
+
+    /// The template which produces this format.
+    pub fn template(&self) -> FormatTemplate {
+        let name = self.to_string();
+        match self {
+            HexFormats::MotorolaSmall1Block => FormatTemplate::new(&name, 32, "", ""),
+            HexFormats::MotorolaSmall2Blocks => FormatTemplate::new(&name, 16, " ", ""),
+            HexFormats::MotorolaSmall4Blocks => FormatTemplate::new(&name, 8, " ", ""),
+            HexFormats::MotorolaSmall1BlockWithX => FormatTemplate::new(&name, 32, "", "0x"),
+            HexFormats::MotorolaSmall2BlocksWithX => FormatTemplate::new(&name, 16, " ", "0x"),
+            HexFormats::MotorolaSmall4BlocksWithX => FormatTemplate::new(&name, 8, " ", "0x"),
+            HexFormats::MotorolaSmall4BlocksWithXWithBrackets => {
+                FormatTemplate::new(&name, 8, " ", "0x").group_brackets("[", "]")
+            }
+            HexFormats::MotorolaArray => {
+                FormatTemplate::new(&name, 8, ", ", "0x").brackets("[", "]")
+            }
+            HexFormats::Intel4Blocks => FormatTemplate::new(&name, 8, " ", "").little_endian(),
+            HexFormats::Intel4BlocksWithX => {
+                FormatTemplate::new(&name, 8, " ", "0x").little_endian()
+            }
+            HexFormats::Intel4BlocksWithXWitchBrackets => FormatTemplate::new(&name, 8, " ", "0x")
+                .little_endian()
+                .group_brackets("[", "]"),
+            HexFormats::IntelArray => FormatTemplate::new(&name, 8, ", ", "0x")
+                .little_endian()
+                .brackets("[", "]"),
+        }
     }
 }
 
@@ -44,7 +88,7 @@ impl std::fmt::Display for HexFormats {
             "{}",
             match self {
                 HexFormats::MotorolaSmall1Block => "0011eeff",
-                HexFormats::MotorolaSmall2Blocks => "0011 ee ff",
+                HexFormats::MotorolaSmall2Blocks => "0011 eeff",
                 HexFormats::MotorolaSmall4Blocks => "00 11 ee ff",
                 HexFormats::MotorolaSmall1BlockWithX => "0x0011eeff",
                 HexFormats::MotorolaSmall2BlocksWithX => "0x0011 0xeeff",
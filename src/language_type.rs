@@ -1,9 +1,63 @@
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub(crate) enum LanguageType {
-    English,
-    Deutsch,
-}
+use fluent_templates::LanguageIdentifier;
+use num_format::Locale;
+use serde::{Deserialize, Serialize};
+
+use crate::translations::{translations, ENGLISH};
+
+/// A language of the user interface, identified by its tag, e.g. `de`.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub(crate) struct LanguageType(pub LanguageIdentifier);
 
 impl LanguageType {
-    pub const ALL: [Self; 2] = [Self::English, Self::Deutsch];
+    /// Every language with a translation.
+    pub fn all() -> Vec<Self> {
+        translations().languages().into_iter().map(Self).collect()
+    }
+
+    /// The language of the operating system, if there is a translation.
+    pub fn system() -> Self {
+        Self(translations().system_language())
+    }
+
+    /// The number locale of the language, e.g. `de` for German.
+    pub fn number_locale(&self) -> Locale {
+        Locale::from_name(self.0.to_string())
+            .or_else(|_| Locale::from_name(self.0.language.as_str()))
+            .unwrap_or(Locale::en)
+    }
+}
+
+impl Default for LanguageType {
+    fn default() -> Self {
+        Self(ENGLISH)
+    }
+}
+
+impl std::fmt::Display for LanguageType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", translations().language_name(&self.0))
+    }
+}
+
+impl From<LanguageType> for String {
+    fn from(language: LanguageType) -> Self {
+        language.0.to_string()
+    }
+}
+
+impl TryFrom<String> for LanguageType {
+    type Error = String;
+
+    /// Reads a language tag, or the names of the languages before tags were used.
+    fn try_from(tag: String) -> Result<Self, Self::Error> {
+        match tag.as_str() {
+            "English" => Ok(Self(ENGLISH)),
+            "Deutsch" => Ok(Self(fluent_templates::loader::langid!("de"))),
+            _ => tag
+                .parse()
+                .map(Self)
+                .map_err(|error| format!("{tag}: {error}")),
+        }
+    }
 }
@@ -0,0 +1,21 @@
+//! The conversions of the binary calculator without a user interface: the
+//! value model and its operations, the parsers of typed and pasted values
+//! and the copy formats. The iced frontend is built on top of it, other
+//! tools can use it to convert exactly like the calculator.
+
+pub mod bin_formats;
+pub mod code_languages;
+pub mod color_formats;
+pub mod copy_format;
+pub mod dec_formats;
+pub mod format_template;
+pub mod hex_formats;
+pub mod literal_formats;
+pub mod magnitude;
+pub mod oct_formats;
+pub mod paste_parser;
+pub mod permission_modes;
+pub mod report;
+pub mod subnet;
+pub mod timestamp_formats;
+pub mod value;
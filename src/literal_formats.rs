@@ -0,0 +1,129 @@
+/// Formats which write the value as a typed literal of a programming or
+/// hardware description language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LiteralFormats {
+    #[default]
+    C,
+    Rust,
+    Python,
+    Go,
+    Java,
+    BinaryWithUnderscores,
+    Verilog,
+    Vhdl,
+    CByteArray,
+    RustByteArray,
+    PythonBytes,
+    GoByteSlice,
+    JavaByteArray,
+}
+
+impl LiteralFormats {
+    pub const ALL: [LiteralFormats; 13] = [
+        LiteralFormats::C,
+        LiteralFormats::Rust,
+        LiteralFormats::Python,
+        LiteralFormats::Go,
+        LiteralFormats::Java,
+        LiteralFormats::BinaryWithUnderscores,
+        LiteralFormats::Verilog,
+        LiteralFormats::Vhdl,
+        LiteralFormats::CByteArray,
+        LiteralFormats::RustByteArray,
+        LiteralFormats::PythonBytes,
+        LiteralFormats::GoByteSlice,
+        LiteralFormats::JavaByteArray,
+    ];
+
+    /// The name of the format on the command line, e.g. `rust-bytes`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LiteralFormats::C => "c",
+            LiteralFormats::Rust => "rust",
+            LiteralFormats::Python => "python",
+            LiteralFormats::Go => "go",
+            LiteralFormats::Java => "java",
+            LiteralFormats::BinaryWithUnderscores => "bin-underscores",
+            LiteralFormats::Verilog => "verilog",
+            LiteralFormats::Vhdl => "vhdl",
+            LiteralFormats::CByteArray => "c-bytes",
+            LiteralFormats::RustByteArray => "rust-bytes",
+            LiteralFormats::PythonBytes => "python-bytes",
+            LiteralFormats::GoByteSlice => "go-bytes",
+            LiteralFormats::JavaByteArray => "java-bytes",
+        }
+    }
+
+    pub fn format(&self, value: u32) -> String {
+        let bytes = value.to_be_bytes();
+        let byte_list = |format: fn(u8) -> String| {
+            bytes
+                .iter()
+                .map(|byte| format(*byte))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match self {
+            LiteralFormats::C => format!("{value:#010X}u"),
+            LiteralFormats::Rust => format!("0x{:04x}_{:04x}_u32", value >> 16, value & 0xFFFF),
+            LiteralFormats::Python | LiteralFormats::Go | LiteralFormats::Java => {
+                format!("{value:#010X}")
+            }
+            LiteralFormats::BinaryWithUnderscores => format!(
+                "0b{}",
+                (0..8)
+                    .rev()
+                    .map(|nibble| format!("{:04b}", (value >> (nibble * 4)) & 0xF))
+                    .collect::<Vec<_>>()
+                    .join("_")
+            ),
+            LiteralFormats::Verilog => {
+                format!("32'h{:04X}_{:04X}", value >> 16, value & 0xFFFF)
+            }
+            LiteralFormats::Vhdl => format!("x\"{value:08X}\""),
+            LiteralFormats::CByteArray => format!(
+                "uint8_t buf[] = {{{}}};",
+                byte_list(|byte| format!("{byte:#04x}"))
+            ),
+            LiteralFormats::RustByteArray => format!(
+                "let buf: [u8; 4] = [{}];",
+                byte_list(|byte| format!("{byte:#04x}"))
+            ),
+            LiteralFormats::PythonBytes => format!(
+                "buf = b\"{}\"",
+                bytes
+                    .iter()
+                    .map(|byte| format!("\\x{byte:02x}"))
+                    .collect::<String>()
+            ),
+            LiteralFormats::GoByteSlice => format!(
+                "buf := []byte{{{}}}",
+                byte_list(|byte| format!("{byte:#04x}"))
+            ),
+            LiteralFormats::JavaByteArray => format!(
+                "byte[] buf = {{{}}};",
+                byte_list(|byte| if byte > 0x7F {
+                    format!("(byte) {byte:#04x}")
+                } else {
+                    format!("{byte:#04x}")
+                })
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for LiteralFormats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let language = match self {
+            LiteralFormats::C | LiteralFormats::CByteArray => "C",
+            LiteralFormats::Rust | LiteralFormats::RustByteArray => "Rust",
+            LiteralFormats::Python | LiteralFormats::PythonBytes => "Python",
+            LiteralFormats::Go | LiteralFormats::GoByteSlice => "Go",
+            LiteralFormats::Java | LiteralFormats::JavaByteArray => "Java",
+            LiteralFormats::BinaryWithUnderscores => "Rust/Python/Java",
+            LiteralFormats::Verilog => "Verilog",
+            LiteralFormats::Vhdl => "VHDL",
+        };
+        write!(f, "{}: {}", language, self.format(0x0011eeff))
+    }
+}
@@ -0,0 +1,134 @@
+/// The SI prefixes, powers of 1000, up to the largest `i64`.
+const SI_PREFIXES: [&str; 7] = ["", "k", "M", "G", "T", "P", "E"];
+/// The IEC binary prefixes, powers of 1024.
+const IEC_PREFIXES: [&str; 4] = ["", "Ki", "Mi", "Gi"];
+
+/// Writes the value in scientific notation, e.g. `1.175295e6`.
+pub fn scientific(value: i64) -> String {
+    let (sign, digits) = split_sign(value);
+    let exponent = digits.len() - 1;
+    format!("{sign}{}e{exponent}", mantissa(&digits, 1))
+}
+
+/// Writes the value in engineering notation, the exponent a multiple of
+/// three, e.g. `1.175295e6`.
+pub fn engineering(value: i64) -> String {
+    let (sign, digits) = split_sign(value);
+    let exponent = (digits.len() - 1) / 3 * 3;
+    format!(
+        "{sign}{}e{exponent}",
+        mantissa(&digits, digits.len() - exponent)
+    )
+}
+
+/// Writes the value with an SI prefix, e.g. `1.175295M`.
+pub fn si(value: i64) -> String {
+    let (sign, digits) = split_sign(value);
+    let power = (digits.len() - 1) / 3;
+    format!(
+        "{sign}{}{}",
+        mantissa(&digits, digits.len() - power * 3),
+        SI_PREFIXES[power]
+    )
+}
+
+/// Writes the value with an IEC binary prefix, e.g. `64Ki`. Values which
+/// are no multiple of the prefix are rounded to three decimals and marked
+/// with `~`, e.g. `~1.121Mi`.
+pub fn iec(value: i64) -> String {
+    let sign = if value < 0 { "-" } else { "" };
+    let magnitude = value.unsigned_abs();
+    let power = (1..IEC_PREFIXES.len())
+        .rev()
+        .find(|power| magnitude >= 1 << (10 * power))
+        .unwrap_or(0);
+    let unit = 1_u64 << (10 * power);
+    let prefix = IEC_PREFIXES[power];
+    if magnitude.is_multiple_of(unit) {
+        return format!("{sign}{}{prefix}", magnitude / unit);
+    }
+    let scaled = format!("{:.3}", magnitude as f64 / unit as f64);
+    let scaled = scaled.trim_end_matches('0').trim_end_matches('.');
+    format!("~{sign}{scaled}{prefix}")
+}
+
+/// Reads a number with an optional SI or IEC prefix or exponent, e.g.
+/// `64Ki`, `1.5M`, `4 GiB` or `1.5e6`. `K` is read as `k`, a trailing `B`
+/// for bytes is ignored.
+///
+/// Returns `None` if the text is no such number or does not fit into 32 bits.
+pub fn parse_magnitude(input: &str) -> Option<u32> {
+    let input = input.trim();
+    let input = input.strip_suffix('B').unwrap_or(input).trim_end();
+    let split = input
+        .find(|c: char| c.is_ascii_alphabetic() && c != 'e' && c != 'E')
+        .unwrap_or(input.len());
+    let (number, prefix) = input.split_at(split);
+    let factor = match prefix {
+        "" => 1,
+        "k" | "K" => 1_000,
+        "M" => 1_000_000,
+        "G" => 1_000_000_000,
+        "Ki" => 1 << 10,
+        "Mi" => 1 << 20,
+        "Gi" => 1 << 30,
+        _ => return None,
+    };
+    let number: f64 = number.trim_end().parse().ok()?;
+    let value = (number * factor as f64).round();
+    if number.is_finite() && (0.0..=u32::MAX as f64).contains(&value) {
+        Some(value as u32)
+    } else {
+        None
+    }
+}
+
+fn split_sign(value: i64) -> (&'static str, String) {
+    let sign = if value < 0 { "-" } else { "" };
+    (sign, value.unsigned_abs().to_string())
+}
+
+/// Writes `digits` with a decimal point after the first `integer` digits,
+/// without trailing zeros.
+fn mantissa(digits: &str, integer: usize) -> String {
+    let (integer, fraction) = digits.split_at(integer);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{integer}.{fraction}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_prefixes() {
+        assert_eq!(parse_magnitude("64Ki"), Some(65_536));
+        assert_eq!(parse_magnitude("1.5M"), Some(1_500_000));
+        assert_eq!(parse_magnitude("2K"), Some(2_000));
+        assert_eq!(parse_magnitude("3 GiB"), Some(3 << 30));
+        assert_eq!(parse_magnitude("1.5e6"), Some(1_500_000));
+        assert_eq!(parse_magnitude("4Gi"), None);
+        assert_eq!(parse_magnitude("-1"), None);
+        assert_eq!(parse_magnitude("1Ti"), None);
+        assert_eq!(parse_magnitude("Ki"), None);
+    }
+
+    #[test]
+    fn format_prefixes() {
+        assert_eq!(scientific(1_175_295), "1.175295e6");
+        assert_eq!(scientific(-5), "-5e0");
+        assert_eq!(engineering(123_456), "123.456e3");
+        assert_eq!(si(1_175_295), "1.175295M");
+        assert_eq!(si(999), "999");
+        assert_eq!(si(i64::MAX), "9.223372036854775807E");
+        assert_eq!(si(i64::MIN), "-9.223372036854775808E");
+        assert_eq!(iec(65_536), "64Ki");
+        assert_eq!(iec(1_175_295), "~1.121Mi");
+        assert_eq!(iec(-2048), "-2Ki");
+        assert_eq!(iec(i64::MIN), "-8589934592Gi");
+    }
+}
@@ -1,34 +1,112 @@
 mod binary_field_widget;
-mod dec_formats;
-mod hex_formats;
+mod cli;
+mod clipboard;
+mod color;
+mod config;
+mod custom_theme;
 mod language_type;
+mod mask;
 mod messages;
+mod network;
+mod notification;
+mod number_locale;
 mod numeric_input_widget;
+mod permissions;
+mod remote;
+mod repl;
 mod settings;
 mod theme_type;
+mod timestamp;
+mod translations;
+mod tui;
+mod ui_scale;
 
+use binary_calculator::copy_format::CopyFormat;
+use binary_calculator::dec_formats::DecFormats;
+use binary_calculator::magnitude;
+use binary_calculator::paste_parser::parse_pasted;
+use binary_calculator::report::ReportFormats;
+use binary_calculator::subnet::Subnet;
+use binary_calculator::value::{self, Operation, Value};
 use binary_field_widget::BinaryFieldWidget;
-use cli_clipboard::{ClipboardContext, ClipboardProvider};
-use dec_formats::DecFormats;
-use hex_formats::HexFormats;
+use clap::Parser;
+use color::ColorPanel;
+use config::Config;
 use iced::theme::Theme;
 use iced::widget::{
-    button, column, container, pick_list, row, text, text_input, toggler, vertical_rule,
+    button, column, container, horizontal_rule, pick_list, row, scrollable, text, text_input,
+    toggler, vertical_rule, Column, Row,
 };
-use iced::{window, Alignment, Element, Length, Sandbox, Settings};
+use iced::{
+    executor, subscription, window, Alignment, Application, Command, Element, Event, Length,
+    Settings, Subscription,
+};
+use mask::MaskPanel;
 use messages::Message;
-use num_format::{Locale, ToFormattedString};
+use network::NetworkPanel;
+use notification::Notification;
+use num_format::Locale;
 use numeric_input_widget::{InputType, NumericInputWidget};
+use permissions::PermissionsPanel;
+use remote::{Call, RemoteCall};
 use settings::BinaryCalulatorSettings;
+use timestamp::TimestampPanel;
+use translations::{tr, tr_args, Translated};
+
+/// The smallest size of the window.
+const MIN_SIZE: (u32, u32) = (420, 320);
+/// Below this width the bits wrap into two rows and the panels are stacked.
+const NARROW_WIDTH: u32 = 860;
 
 pub fn main() -> iced::Result {
+    let args = cli::Args::parse();
+    if args.repl {
+        std::process::exit(match cli::initial_value(&args) {
+            Ok(value) => repl::run(value),
+            Err(code) => code,
+        });
+    }
+    // The conversions only read the settings, the window may move them aside.
+    if args.pipe {
+        std::process::exit(cli::pipe(&args));
+    }
+    if args.value.is_some() {
+        std::process::exit(cli::run(&args));
+    }
+    let config = Config::load();
+    let custom_themes: Vec<String> = config
+        .as_ref()
+        .map(|config| {
+            config
+                .custom_themes
+                .iter()
+                .map(|theme| theme.name.clone())
+                .collect()
+        })
+        .unwrap_or_default();
+    let messages = match cli::startup_messages(&args, &custom_themes) {
+        Ok(messages) => messages,
+        Err(code) => std::process::exit(code),
+    };
+    if args.tui {
+        std::process::exit(tui::run(config, messages));
+    }
+    let geometry = config
+        .as_ref()
+        .map(|config| config.window)
+        .unwrap_or_default();
     let settings = Settings {
         window: window::Settings {
-            size: (885, 450),
-            resizable: false,
+            size: (geometry.width, geometry.height),
+            position: match (geometry.x, geometry.y) {
+                (Some(x), Some(y)) => window::Position::Specific(x, y),
+                _ => window::Position::Default,
+            },
+            min_size: Some(MIN_SIZE),
             ..Default::default()
         },
-        ..Default::default()
+        exit_on_close_request: false,
+        ..Settings::with_flags((config, messages))
     };
     BinaryCalculator::run(settings)
 }
@@ -36,8 +114,27 @@ pub fn main() -> iced::Result {
 struct BinaryCalculator {
     value: u32,
     signed: bool,
+    /// The width of the word in bits, the bits above it are always clear.
+    width: u32,
+    /// The text of the prefix input, e.g. `64Ki`.
+    magnitude_input: String,
+    /// The copy formats used last, at most one per radix.
+    copy_formats: Vec<CopyFormat>,
+    /// The decimal copy format used last.
+    dec_format: Option<DecFormats>,
+    /// The settings to be saved, updated when the window closes.
+    config: Config,
+    /// The result of the last copy or paste.
+    notification: Option<Notification>,
+    /// The width of the window in units of the scaled layout.
+    layout_width: u32,
     page: Pages,
     settings: BinaryCalulatorSettings,
+    mask: MaskPanel,
+    timestamp: TimestampPanel,
+    color: ColorPanel,
+    permissions: PermissionsPanel,
+    network: NetworkPanel,
 }
 
 impl Default for BinaryCalculator {
@@ -45,90 +142,234 @@ impl Default for BinaryCalculator {
         Self {
             value: Default::default(),
             signed: false,
+            width: value::MAX_WIDTH,
+            magnitude_input: String::new(),
+            copy_formats: Vec::new(),
+            dec_format: None,
+            config: Config::default(),
+            notification: None,
+            layout_width: config::WindowGeometry::default().width,
             page: Pages::default(),
             settings: BinaryCalulatorSettings::new(),
+            mask: MaskPanel::new(),
+            timestamp: TimestampPanel::new(),
+            color: ColorPanel::new(),
+            permissions: PermissionsPanel::new(),
+            network: NetworkPanel::new(),
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, clap::ValueEnum)]
 enum Pages {
+    #[default]
     Main,
+    Network,
+    Permissions,
+    Color,
+    Timestamp,
+    Mask,
     Settings,
 }
 
-impl Default for Pages {
-    fn default() -> Self {
-        Self::Main
+impl Pages {
+    /// The message which shows the page.
+    fn message(self) -> Message {
+        match self {
+            Pages::Main => Message::Main,
+            Pages::Network => Message::Network,
+            Pages::Permissions => Message::Permissions,
+            Pages::Color => Message::Color,
+            Pages::Timestamp => Message::Timestamp,
+            Pages::Mask => Message::Mask,
+            Pages::Settings => Message::Settings,
+        }
     }
 }
 
-impl Sandbox for BinaryCalculator {
+impl Application for BinaryCalculator {
+    type Executor = executor::Default;
     type Message = Message;
+    type Theme = Theme;
+    /// The saved settings, or why they could not be read, and the messages
+    /// which apply the command line arguments.
+    type Flags = (Result<Config, config::ConfigError>, Vec<Message>);
 
-    fn new() -> Self {
-        BinaryCalculator::default()
+    fn new((config, messages): Self::Flags) -> (Self, Command<Message>) {
+        let mut calculator = BinaryCalculator::default();
+        match config {
+            Ok(config) => calculator.apply_config(config),
+            Err(error) => calculator.notification = Some(Notification::error(error.to_string())),
+        }
+        for message in messages {
+            let _ = calculator.update(message);
+        }
+        (calculator, Command::none())
     }
 
     fn title(&self) -> String {
-        String::from("Binary Calculator")
+        tr("title")
     }
 
-    fn update(&mut self, message: Message) {
+    fn update(&mut self, message: Message) -> Command<Message> {
+        let previous_value = self.value;
+        let magnitude_typed = matches!(message, Message::MagnitudeInputChanged(_));
         match message {
-            Message::ShiftLeft => self.value <<= 1,
-            Message::ShiftRight => self.value >>= 1,
-            Message::Not => self.value = !self.value,
-            Message::DecInputChanged(value) => {
-                if value.is_empty() {
-                    self.value = 0
-                } else if let Ok(val) = value.replace(['.', ',', ' '], "").parse() {
-                    self.value = val;
+            Message::Apply(operation) => self.apply(operation),
+            Message::Paste => match clipboard::paste() {
+                Ok(contents) => self.paste(&contents),
+                Err(error) => self.notification = Some(Notification::error(error.to_string())),
+            },
+            Message::DecInputChanged(input) => {
+                if let Some(value) = value::parse_decimal(&input, &self.settings.number_locale()) {
+                    self.apply(Operation::Set(value));
                 }
             }
-            Message::HexInputChanged(value) => {
-                if value.is_empty() {
-                    self.value = 0
-                } else if let Ok(val) = u32::from_str_radix(&value.replace(['.', ',', ' '], ""), 16)
-                {
-                    self.value = val;
+            Message::MagnitudeInputChanged(input) => {
+                if let Some(value) = magnitude::parse_magnitude(&input) {
+                    self.value = value;
                 }
+                self.magnitude_input = input;
             }
-            Message::HexCopy(format) => self.copy_hex_to_clipboard(format),
-            Message::DecCopy(format) => {
-                if let Ok(mut clipboard) = ClipboardContext::new() {
-                    match format {
-                        DecFormats::Plain => {
-                            clipboard.set_contents(self.value.to_string()).unwrap()
-                        }
-                        DecFormats::PointSeperator => clipboard
-                            .set_contents(self.value.to_formatted_string(&Locale::de))
-                            .unwrap(),
-                        DecFormats::CommaSeperator => clipboard
-                            .set_contents(self.value.to_formatted_string(&Locale::en))
-                            .unwrap(),
-                    }
+            Message::HexInputChanged(input) => {
+                if let Some(value) = value::parse_hexadecimal(&input) {
+                    self.apply(Operation::Set(value));
                 }
             }
+            Message::Copy(format) => self.copy_to_clipboard(format),
+            Message::CopyReport(format) => {
+                self.copy_text(format.format(self.word(), self.mask.fields()))
+            }
+            Message::DecCopy(format) => {
+                self.copy_text(format.format(self.decimal_value(), &self.settings.number_locale()))
+            }
             Message::SignToggled(value) => self.signed = value,
+            Message::WidthChanged(width) => {
+                let word = self.word().with_width(width);
+                self.width = word.width();
+                self.value = word.bits();
+            }
+            Message::Permissions => self.page = Pages::Permissions,
+            Message::Color => self.page = Pages::Color,
+            Message::Timestamp => self.page = Pages::Timestamp,
+            Message::Mask => self.page = Pages::Mask,
             Message::Settings => self.page = Pages::Settings,
             Message::Main => self.page = Pages::Main,
+            Message::Network => self.page = Pages::Network,
             Message::InputU32Changed(value) => self.value = value,
-            Message::SettingsMessage(msg) => self.settings.update(msg),
+            Message::SettingsMessage(msg) => {
+                self.settings.update(msg);
+                self.fit_layout();
+            }
+            Message::NotificationDismissed => self.notification = None,
+            Message::MaskMessage(msg) => {
+                if let Some(code) = self.mask.update(msg, &mut self.value) {
+                    self.copy_text(code);
+                }
+            }
+            Message::TimestampMessage(msg) => self.timestamp.update(msg, &mut self.value),
+            Message::ColorMessage(msg) => self.color.update(msg, &mut self.value),
+            Message::PermissionsMessage(msg) => self.permissions.update(msg, &mut self.value),
+            Message::NetworkMessage(msg) => self.network.update(msg, &mut self.value),
+            Message::WindowResized(width, height) => {
+                // The window is sized in display units, without the zoom.
+                let factor = self.settings.scale().factor();
+                self.layout_width = width;
+                self.config.window.width = (f64::from(width) * factor).round() as u32;
+                self.config.window.height = (f64::from(height) * factor).round() as u32;
+            }
+            Message::WindowMoved(x, y) => {
+                self.config.window.x = Some(x);
+                self.config.window.y = Some(y);
+            }
+            Message::CloseRequested => {
+                remote::close();
+                self.store_config();
+                if let Err(error) = self.config.save() {
+                    eprintln!("{error}");
+                }
+                return window::close();
+            }
+            Message::Remote(call) => self.answer_remote(call),
         }
+        self.value &= value::mask(self.width);
+        // The typed magnitude no longer describes a value changed otherwise.
+        if self.value != previous_value && !magnitude_typed {
+            self.magnitude_input.clear();
+        }
+        Command::none()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        let window_events = subscription::events_with(|event, _status| match event {
+            Event::Window(window::Event::Resized { width, height }) => {
+                Some(Message::WindowResized(width, height))
+            }
+            Event::Window(window::Event::Moved { x, y }) => Some(Message::WindowMoved(x, y)),
+            Event::Window(window::Event::CloseRequested) => Some(Message::CloseRequested),
+            _ => None,
+        });
+        Subscription::batch([window_events, remote::subscription()])
     }
 
-    fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         let main_button = button(self.settings.main_str()).on_press(Message::Main);
+        let network_button = button(self.settings.network_str()).on_press(Message::Network);
+        let permissions_button =
+            button(self.settings.permissions_str()).on_press(Message::Permissions);
+        let color_button = button(self.settings.color_str()).on_press(Message::Color);
+        let timestamp_button = button(self.settings.timestamp_str()).on_press(Message::Timestamp);
+        let mask_button = button(self.settings.mask_str()).on_press(Message::Mask);
         let settings_button = button(self.settings.setting_str()).on_press(Message::Settings);
-        let header_row = row![main_button, settings_button].spacing(10);
+        let narrow = self.layout_width < NARROW_WIDTH;
+        let header_row: Element<Message> = if narrow {
+            column![
+                row![
+                    main_button,
+                    network_button,
+                    permissions_button,
+                    color_button
+                ]
+                .spacing(10),
+                row![timestamp_button, mask_button, settings_button].spacing(10),
+            ]
+            .spacing(10)
+            .into()
+        } else {
+            row![
+                main_button,
+                network_button,
+                permissions_button,
+                color_button,
+                timestamp_button,
+                mask_button,
+                settings_button
+            ]
+            .spacing(10)
+            .into()
+        };
         let content: Element<Message> = match self.page {
             Pages::Main => {
-                let shift_left_button = button("<<").on_press(Message::ShiftLeft);
-                let shift_right_button = button(">>").on_press(Message::ShiftRight);
-                let not_button = button("Not").on_press(Message::Not);
+                let shift_left_button =
+                    button("<<").on_press(Message::Apply(Operation::ShiftLeft(1)));
+                let shift_right_button =
+                    button(">>").on_press(Message::Apply(Operation::ShiftRight(1)));
+                let not_button = button(text(tr("not"))).on_press(Message::Apply(Operation::Not));
+                let paste_button = button(text(tr("paste"))).on_press(Message::Paste);
+                let report_pick_list = pick_list(
+                    ReportFormats::ALL.map(Translated).to_vec(),
+                    None,
+                    |format| Message::CopyReport(format.0),
+                )
+                .placeholder(tr("copy_all"));
                 let binary_field_widget =
-                    BinaryFieldWidget::new(self.value, Message::InputU32Changed);
+                    BinaryFieldWidget::new(self.value, Message::InputU32Changed)
+                        .highlight(if self.width < value::MAX_WIDTH {
+                            value::mask(self.width)
+                        } else {
+                            0
+                        })
+                        .wrap(narrow);
                 let hexadecimal_text_input = text_input(
                     "",
                     format!(
@@ -139,30 +380,41 @@ impl Sandbox for BinaryCalculator {
                     .as_str(),
                     Message::HexInputChanged,
                 )
-                .width(Length::Units(200));
-                let hex_pick_list = pick_list(&HexFormats::ALL[..], None, Message::HexCopy)
-                    .placeholder(self.settings.copy_to_clipboard_str())
-                    .width(Length::Units(200));
+                .width(Length::Fill);
+                let hex_pick_list = pick_list(
+                    CopyFormat::all(16, self.settings.copy_templates()),
+                    self.copy_format(16),
+                    Message::Copy,
+                )
+                .placeholder(self.settings.copy_to_clipboard_str())
+                .width(Length::Fill);
                 let signed_toogler = toggler(
-                    if self.signed {
-                        "signed".to_string()
-                    } else {
-                        "unsigned".to_string()
-                    },
+                    tr(if self.signed { "signed" } else { "unsigned" }),
                     self.signed,
                     Message::SignToggled,
                 )
                 .width(Length::Shrink);
-                let value = if self.signed {
-                    (self.value as i32).to_formatted_string(&Locale::de)
-                } else {
-                    self.value.to_formatted_string(&Locale::de)
-                };
+                let value = DecFormats::LocaleSeperator
+                    .format(self.decimal_value(), &self.settings.number_locale());
                 let decimal_text_input =
-                    text_input("", &value, Message::DecInputChanged).width(Length::Units(150));
-                let dec_pick_list = pick_list(&DecFormats::ALL[..], None, Message::DecCopy)
-                    .placeholder(self.settings.copy_to_clipboard_str())
-                    .width(Length::Units(200));
+                    text_input("", &value, Message::DecInputChanged).width(Length::Fill);
+                let magnitude_text_input = text_input(
+                    &format!(
+                        "{} = {}",
+                        DecFormats::SiPrefix.format(self.value as i64, &Locale::en),
+                        DecFormats::IecPrefix.format(self.value as i64, &Locale::en)
+                    ),
+                    &self.magnitude_input,
+                    Message::MagnitudeInputChanged,
+                )
+                .width(Length::Fill);
+                let dec_pick_list = pick_list(
+                    DecFormats::ALL.map(Translated).to_vec(),
+                    self.dec_format.map(Translated),
+                    |format| Message::DecCopy(format.0),
+                )
+                .placeholder(self.settings.copy_to_clipboard_str())
+                .width(Length::Fill);
                 let decimal_input_widget = NumericInputWidget::new(
                     self.value,
                     InputType::Decimal,
@@ -175,6 +427,20 @@ impl Sandbox for BinaryCalculator {
                 );
                 let octal_input_widget =
                     NumericInputWidget::new(self.value, InputType::Octal, Message::InputU32Changed);
+                let octal_pick_list = pick_list(
+                    CopyFormat::all(8, self.settings.copy_templates()),
+                    self.copy_format(8),
+                    Message::Copy,
+                )
+                .placeholder(self.settings.copy_to_clipboard_str())
+                .width(Length::Fill);
+                let binary_pick_list = pick_list(
+                    CopyFormat::all(2, self.settings.copy_templates()),
+                    self.copy_format(2),
+                    Message::Copy,
+                )
+                .placeholder(self.settings.copy_to_clipboard_str())
+                .width(Length::Fill);
                 let octal_text_input = text_input(
                     "",
                     format!(
@@ -187,49 +453,130 @@ impl Sandbox for BinaryCalculator {
                     .as_str(),
                     Message::DecInputChanged,
                 )
-                .width(Length::Units(150));
-                column![
+                .width(Length::Fill);
+                let bit_row: Element<Message> = if narrow {
+                    column![
+                        binary_field_widget,
+                        row![
+                            shift_left_button,
+                            shift_right_button,
+                            not_button,
+                            paste_button,
+                            report_pick_list,
+                        ]
+                        .spacing(4)
+                        .align_items(Alignment::Center),
+                    ]
+                    .spacing(10)
+                    .into()
+                } else {
                     row![
                         shift_left_button,
                         binary_field_widget,
                         shift_right_button,
                         not_button,
+                        paste_button,
+                        report_pick_list,
                     ]
                     .spacing(4)
-                    .align_items(Alignment::Center),
-                    row![
-                        column![
-                            text(self.settings.hexadecimal_str()),
-                            hexadecimal_text_input,
-                            hex_pick_list,
-                            hex_input_widget,
-                        ]
-                        .spacing(10),
-                        vertical_rule(38),
-                        column![
-                            row![text(self.settings.decimal_str()), signed_toogler].spacing(10),
-                            decimal_text_input,
-                            dec_pick_list,
-                            decimal_input_widget,
-                        ]
-                        .spacing(10),
-                        vertical_rule(38),
-                        column![
-                            text(self.settings.octal_str()),
-                            octal_text_input,
-                            octal_input_widget
-                        ]
-                        .spacing(10),
-                    ]
-                    .spacing(20),
+                    .align_items(Alignment::Center)
+                    .into()
+                };
+                let panels = [
+                    column![
+                        text(self.settings.hexadecimal_str()),
+                        hexadecimal_text_input,
+                        hex_pick_list,
+                        hex_input_widget,
+                    ],
+                    column![
+                        row![text(self.settings.decimal_str()), signed_toogler].spacing(10),
+                        decimal_text_input,
+                        magnitude_text_input,
+                        dec_pick_list,
+                        decimal_input_widget,
+                    ],
+                    column![
+                        text(self.settings.octal_str()),
+                        octal_text_input,
+                        octal_pick_list,
+                        octal_input_widget,
+                        text(self.settings.binary_str()),
+                        binary_pick_list,
+                    ],
+                ]
+                .map(|panel| panel.spacing(10).width(Length::Fill));
+                // Side by side, or one below the other on narrow windows.
+                let panels: Element<Message> = if narrow {
+                    panels
+                        .into_iter()
+                        .enumerate()
+                        .fold(Column::new().spacing(20), |column, (index, panel)| {
+                            let column = if index > 0 {
+                                column.push(horizontal_rule(1))
+                            } else {
+                                column
+                            };
+                            column.push(panel)
+                        })
+                        .into()
+                } else {
+                    panels
+                        .into_iter()
+                        .enumerate()
+                        // Filled, the rules take the height of the panels.
+                        .fold(
+                            Row::new().spacing(20).align_items(Alignment::Fill),
+                            |row, (index, panel)| {
+                                let row = if index > 0 {
+                                    row.push(vertical_rule(38))
+                                } else {
+                                    row
+                                };
+                                row.push(panel)
+                            },
+                        )
+                        .into()
+                };
+                column![bit_row, panels].spacing(20).max_width(900).into()
+            }
+            Pages::Network => {
+                let subnet = Subnet::new(self.value, self.network.prefix());
+                let binary_field_widget =
+                    BinaryFieldWidget::new(self.value, Message::InputU32Changed)
+                        .highlight(subnet.mask())
+                        .wrap(narrow);
+                column![
+                    binary_field_widget,
+                    self.network.view(self.value).map(Message::NetworkMessage)
                 ]
                 .spacing(20)
                 .max_width(900)
                 .into()
             }
+            Pages::Permissions => self
+                .permissions
+                .view(self.value)
+                .map(Message::PermissionsMessage),
+            Pages::Color => self.color.view(self.value).map(Message::ColorMessage),
+            Pages::Timestamp => self
+                .timestamp
+                .view(self.value)
+                .map(Message::TimestampMessage),
+            Pages::Mask => self.mask.view(self.value, narrow).map(Message::MaskMessage),
             Pages::Settings => self.settings.view().map(Message::SettingsMessage),
         };
-        let content2 = column![header_row, content].padding(20).spacing(20);
+        // The settings scroll by themselves.
+        let content: Element<Message> = if self.page == Pages::Settings {
+            content
+        } else {
+            scrollable(content).into()
+        };
+        let mut content2 = column![header_row, content].padding(20).spacing(20);
+        if let Some(notification) = &self.notification {
+            content2 = content2
+                .push(notification.view(self.settings.theme(), Message::NotificationDismissed));
+        }
         container(content2)
             .width(Length::Fill)
             .height(Length::Fill)
@@ -238,110 +585,164 @@ impl Sandbox for BinaryCalculator {
             .into()
     }
 
+    fn scale_factor(&self) -> f64 {
+        self.settings.scale().factor()
+    }
+
     fn theme(&self) -> Theme {
         self.settings.theme().clone()
     }
 }
 
 impl BinaryCalculator {
-    fn copy_hex_to_clipboard(&self, format: HexFormats) {
-        if let Ok(mut clipboard) = ClipboardContext::new() {
-            match format {
-                HexFormats::MotorolaSmall1Block => {
-                    clipboard
-                        .set_contents(format!("{:08x}", self.value))
-                        .unwrap();
-                }
-                HexFormats::MotorolaSmall2Blocks => clipboard
-                    .set_contents(format!(
-                        "{:04x} {:04x}",
-                        (self.value >> 16) & 0xFFFF,
-                        self.value & 0xFFFF
-                    ))
-                    .unwrap(),
-                HexFormats::MotorolaSmall4Blocks => clipboard
-                    .set_contents(format!(
-                        "{:02x} {:02x} {:02x} {:02x}",
-                        (self.value >> 24) & 0xFF,
-                        (self.value >> 16) & 0xFF,
-                        (self.value >> 8) & 0xFF,
-                        self.value & 0xFF
-                    ))
-                    .unwrap(),
-                HexFormats::MotorolaSmall1BlockWithX => clipboard
-                    .set_contents(format!("{:#010x}", self.value))
-                    .unwrap(),
-                HexFormats::MotorolaSmall2BlocksWithX => clipboard
-                    .set_contents(format!(
-                        "{:#06x} {:#06x}",
-                        (self.value >> 16) & 0xFFFF,
-                        self.value & 0xFFFF
-                    ))
-                    .unwrap(),
-                HexFormats::MotorolaSmall4BlocksWithX => clipboard
-                    .set_contents(format!(
-                        "{:#04x} {:#04x} {:#04x} {:#04x}",
-                        (self.value >> 24) & 0xFF,
-                        (self.value >> 16) & 0xFF,
-                        (self.value >> 8) & 0xFF,
-                        self.value & 0xFF
-                    ))
-                    .unwrap(),
-                HexFormats::MotorolaSmall4BlocksWithXWithBrackets => clipboard
-                    .set_contents(format!(
-                        "[{:#04x}] [{:#04x}] [{:#04x}] [{:#04x}]",
-                        (self.value >> 24) & 0xFF,
-                        (self.value >> 16) & 0xFF,
-                        (self.value >> 8) & 0xFF,
-                        self.value & 0xFF
-                    ))
-                    .unwrap(),
-                HexFormats::MotorolaArray => clipboard
-                    .set_contents(format!(
-                        "[{:#04x}, {:#04x}, {:#04x}, {:#04x}]",
-                        (self.value >> 24) & 0xFF,
-                        (self.value >> 16) & 0xFF,
-                        (self.value >> 8) & 0xFF,
-                        self.value & 0xFF
-                    ))
-                    .unwrap(),
-                HexFormats::Intel4Blocks => clipboard
-                    .set_contents(format!(
-                        "{:04x} {:04x} {:04x} {:04x}",
-                        self.value & 0xFF,
-                        (self.value >> 8) & 0xFF,
-                        (self.value >> 16) & 0xFF,
-                        (self.value >> 24) & 0xFF
-                    ))
-                    .unwrap(),
-                HexFormats::Intel4BlocksWithX => clipboard
-                    .set_contents(format!(
-                        "{:#04x} {:#04x} {:#04x} {:#04x}",
-                        self.value & 0xFF,
-                        (self.value >> 8) & 0xFF,
-                        (self.value >> 16) & 0xFF,
-                        (self.value >> 24) & 0xFF
-                    ))
-                    .unwrap(),
-                HexFormats::Intel4BlocksWithXWitchBrackets => clipboard
-                    .set_contents(format!(
-                        "[{:#04x}] [{:#04x}] [{:#04x}] [{:#04x}]",
-                        self.value & 0xFF,
-                        (self.value >> 8) & 0xFF,
-                        (self.value >> 16) & 0xFF,
-                        (self.value >> 24) & 0xFF
-                    ))
-                    .unwrap(),
-                HexFormats::IntelArray => clipboard
-                    .set_contents(format!(
-                        "[{:#04x}, {:#04x}, {:#04x}, {:#04x}]",
-                        self.value & 0xFF,
-                        (self.value >> 8) & 0xFF,
-                        (self.value >> 16) & 0xFF,
-                        (self.value >> 24) & 0xFF
-                    ))
-                    .unwrap(),
-            };
+    /// The value as shown in the decimal field, depending on the sign mode.
+    fn decimal_value(&self) -> i64 {
+        self.word().decimal()
+    }
+
+    /// The value as word of the library.
+    fn word(&self) -> Value {
+        Value::new(self.value, self.width, self.signed)
+    }
+
+    fn apply(&mut self, operation: Operation) {
+        self.value = self.word().apply(operation).bits();
+    }
+
+    fn paste(&mut self, contents: &str) {
+        let interpretations = parse_pasted(contents);
+        match &interpretations[..] {
+            [] => {
+                self.notification = Some(Notification::error(tr_args(
+                    "paste_unreadable",
+                    &[("text", contents.trim().into())],
+                )))
+            }
+            [interpretation] => {
+                self.value = interpretation.value;
+                self.notification = Some(Notification::success(tr_args(
+                    "pasted",
+                    &[(
+                        "layout",
+                        Translated(interpretation.layout).to_string().into(),
+                    )],
+                )));
+            }
+            [interpretation, others @ ..] => {
+                self.value = interpretation.value;
+                let others = others
+                    .iter()
+                    .map(|other| format!("{:#x} ({})", other.value, Translated(other.layout)))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                self.notification = Some(Notification::success(tr_args(
+                    "paste_ambiguous",
+                    &[
+                        (
+                            "layout",
+                            Translated(interpretation.layout).to_string().into(),
+                        ),
+                        ("others", others.into()),
+                    ],
+                )));
+            }
+        }
+    }
+
+    fn copy_to_clipboard(&mut self, format: CopyFormat) {
+        self.copy_text(format.format(self.value));
+        self.copy_formats
+            .retain(|used| used.radix() != format.radix());
+        self.copy_formats.push(format);
+    }
+
+    /// The copy format used last in the pick list of the radix.
+    fn copy_format(&self, radix: u32) -> Option<CopyFormat> {
+        self.copy_formats
+            .iter()
+            .find(|format| format.radix() == radix)
+            .cloned()
+    }
+
+    /// Takes over the saved settings and value.
+    fn apply_config(&mut self, config: Config) {
+        self.settings.apply_config(&config);
+        self.width = config.width;
+        self.value = config.value & value::mask(self.width);
+        self.signed = config.signed;
+        let formats: Vec<CopyFormat> = [16, 8, 2]
+            .iter()
+            .flat_map(|radix| CopyFormat::all(*radix, self.settings.copy_templates()))
+            .collect();
+        self.copy_formats = config
+            .copy_formats
+            .iter()
+            .filter_map(|label| formats.iter().find(|format| format.to_string() == *label))
+            .cloned()
+            .collect();
+        self.dec_format = DecFormats::ALL
+            .iter()
+            .find(|format| config.copy_formats.contains(&format.to_string()))
+            .copied();
+        self.config = config;
+        self.fit_layout();
+    }
+
+    /// Takes the width of the layout from the size of the window: the window
+    /// keeps its size when the zoom changes, the layout shrinks or grows.
+    fn fit_layout(&mut self) {
+        self.layout_width =
+            (f64::from(self.config.window.width) / self.settings.scale().factor()) as u32;
+    }
+
+    /// Writes the current settings and value into the config to be saved.
+    fn store_config(&mut self) {
+        self.settings.store_config(&mut self.config);
+        self.config.version = Config::VERSION;
+        self.config.value = self.value;
+        self.config.signed = self.signed;
+        self.config.width = self.width;
+        self.config.copy_formats = self
+            .copy_formats
+            .iter()
+            .map(ToString::to_string)
+            .chain(self.dec_format.map(|format| format.to_string()))
+            .collect();
+    }
+
+    /// Answers a call of a remote client by sending the messages of the
+    /// window, so it behaves like the window.
+    fn answer_remote(&mut self, remote: RemoteCall) {
+        let message = match &remote.call {
+            Call::GetValue | Call::Convert(_) => None,
+            Call::SetValue(value) => Some(Message::InputU32Changed(*value)),
+            Call::Apply(operation) => Some(Message::Apply(*operation)),
+            Call::SetSigned(signed) => Some(Message::SignToggled(*signed)),
+        };
+        if let Some(message) = message {
+            let _ = self.update(message);
         }
+        remote.reply(match &remote.call {
+            Call::Convert(name) if name == "dec" => Ok(self.decimal_value().to_string().into()),
+            Call::Convert(name) => CopyFormat::from_name(name, self.settings.copy_templates())
+                .map(|format| format.format(self.value).into())
+                .ok_or_else(|| format!("unknown format `{name}`")),
+            _ => Ok(serde_json::json!({
+                "value": self.value,
+                "signed": self.signed,
+                "decimal": self.decimal_value(),
+            })),
+        });
+    }
+
+    /// Copies the text and reports the result.
+    fn copy_text(&mut self, contents: String) {
+        self.notification = Some(match clipboard::copy(contents.clone()) {
+            Ok(()) => Notification::success(tr_args(
+                "copied",
+                &[("text", contents.lines().next().unwrap_or("").into())],
+            )),
+            Err(error) => Notification::error(error.to_string()),
+        });
     }
 }
@@ -0,0 +1,157 @@
+use iced::widget::{button, column, pick_list, row, text, text_input, Column};
+use iced::{Alignment, Element, Length};
+
+use crate::binary_field_widget::BinaryFieldWidget;
+use crate::translations::{tr, tr_args};
+use binary_calculator::code_languages::CodeLanguages;
+
+/// A named field of a register.
+struct MaskField {
+    name: String,
+    mask: u32,
+}
+
+pub(crate) struct MaskPanel {
+    /// The bits selected in the bit field.
+    selection: u32,
+    /// The fields defined so far.
+    fields: Vec<MaskField>,
+    /// The name of the next field.
+    name: String,
+    language: CodeLanguages,
+}
+
+impl<'a> MaskPanel {
+    pub fn new() -> Self {
+        Self {
+            selection: 0,
+            fields: Vec::new(),
+            name: String::new(),
+            language: CodeLanguages::default(),
+        }
+    }
+
+    /// Handles the message, returns the code to copy for [`MaskMessage::Copy`].
+    pub fn update(&mut self, msg: MaskMessage, value: &mut u32) -> Option<String> {
+        match msg {
+            MaskMessage::SelectionChanged(selection) => self.selection = selection,
+            MaskMessage::SelectionFromValue => self.selection = *value,
+            MaskMessage::NameChanged(name) => self.name = name,
+            MaskMessage::AddField => {
+                if self.selection != 0 {
+                    let name = if self.name.trim().is_empty() {
+                        format!("FIELD{}", self.fields.len())
+                    } else {
+                        self.name.clone()
+                    };
+                    self.fields.push(MaskField {
+                        name,
+                        mask: self.selection,
+                    });
+                    self.selection = 0;
+                    self.name.clear();
+                }
+            }
+            MaskMessage::RemoveField(index) => {
+                if index < self.fields.len() {
+                    self.fields.remove(index);
+                }
+            }
+            MaskMessage::LanguageChanged(language) => self.language = language,
+            MaskMessage::Copy => return Some(self.code()),
+        }
+        None
+    }
+
+    /// Shows the panel, with the bits in two rows if it is `narrow`.
+    pub fn view(&self, value: u32, narrow: bool) -> Element<'a, MaskMessage> {
+        let used = self.fields.iter().fold(0, |used, field| used | field.mask);
+        let binary_field_widget =
+            BinaryFieldWidget::new(self.selection, MaskMessage::SelectionChanged)
+                .highlight(used)
+                .wrap(narrow);
+        let name_input = text_input(&tr("field_name"), &self.name, MaskMessage::NameChanged)
+            .on_submit(MaskMessage::AddField)
+            .width(Length::Units(200));
+        let add_button = button(text(tr("add_field"))).on_press(MaskMessage::AddField);
+        let from_value_button = button(text(tr_args(
+            "from_value",
+            &[("value", format!("{value:#010x}").into())],
+        )))
+        .on_press(MaskMessage::SelectionFromValue);
+        let fields = self.fields.iter().enumerate().fold(
+            Column::new().spacing(5),
+            |column, (index, field)| {
+                column.push(
+                    row![
+                        button("x").on_press(MaskMessage::RemoveField(index)),
+                        text(&field.name).width(Length::Units(200)),
+                        text(format!("{:#010x}", field.mask)),
+                    ]
+                    .spacing(10)
+                    .align_items(Alignment::Center),
+                )
+            },
+        );
+        let language_pick_list = pick_list(
+            &CodeLanguages::ALL[..],
+            Some(self.language),
+            MaskMessage::LanguageChanged,
+        )
+        .width(Length::Units(150));
+        let copy_button = button(text(tr("copy"))).on_press(MaskMessage::Copy);
+        column![
+            binary_field_widget,
+            row![
+                name_input,
+                add_button,
+                from_value_button,
+                text(format!("{:#010x}", self.selection))
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center),
+            row![
+                fields.width(Length::Units(400)),
+                column![
+                    row![language_pick_list, copy_button].spacing(10),
+                    text(self.code())
+                ]
+                .spacing(10),
+            ]
+            .spacing(20),
+        ]
+        .spacing(20)
+        .max_width(900)
+        .into()
+    }
+
+    /// The names and masks of the fields defined so far.
+    pub(crate) fn fields(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.mask))
+    }
+
+    /// The declarations of all fields, or of the selection if there are none.
+    fn code(&self) -> String {
+        if self.fields.is_empty() {
+            return self.language.mask_declaration("FIELD", self.selection);
+        }
+        self.fields
+            .iter()
+            .map(|field| self.language.mask_declaration(&field.name, field.mask))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum MaskMessage {
+    SelectionChanged(u32),
+    SelectionFromValue,
+    NameChanged(String),
+    AddField,
+    RemoveField(usize),
+    LanguageChanged(CodeLanguages),
+    Copy,
+}
@@ -1,17 +1,45 @@
-use crate::{dec_formats::DecFormats, hex_formats::HexFormats, settings::SettingsMessage};
+use binary_calculator::{
+    copy_format::CopyFormat, dec_formats::DecFormats, report::ReportFormats, value::Operation,
+};
+
+use crate::{
+    color::ColorMessage, mask::MaskMessage, network::NetworkMessage,
+    permissions::PermissionsMessage, remote::RemoteCall, settings::SettingsMessage,
+    timestamp::TimestampMessage,
+};
 
 #[derive(Debug, Clone)]
 pub(crate) enum Message {
     DecInputChanged(String),
     HexInputChanged(String),
-    ShiftLeft,
-    ShiftRight,
-    Not,
-    HexCopy(HexFormats),
+    MagnitudeInputChanged(String),
+    /// Changes the value, e.g. shifts it.
+    Apply(Operation),
+    Paste,
+    NotificationDismissed,
+    Copy(CopyFormat),
+    CopyReport(ReportFormats),
     DecCopy(DecFormats),
     SignToggled(bool),
+    /// Changes the width of the word in bits.
+    WidthChanged(u32),
     Settings,
     Main,
+    Network,
+    Permissions,
+    Color,
+    Timestamp,
+    Mask,
     InputU32Changed(u32),
+    WindowResized(u32, u32),
+    WindowMoved(i32, i32),
+    CloseRequested,
+    /// A call of a client of the automation socket.
+    Remote(RemoteCall),
     SettingsMessage(SettingsMessage),
+    NetworkMessage(NetworkMessage),
+    PermissionsMessage(PermissionsMessage),
+    ColorMessage(ColorMessage),
+    TimestampMessage(TimestampMessage),
+    MaskMessage(MaskMessage),
 }
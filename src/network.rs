@@ -0,0 +1,105 @@
+use std::net::Ipv4Addr;
+
+use crate::translations::tr;
+use binary_calculator::subnet::{parse_cidr, Subnet};
+use iced::widget::{column, row, slider, text, text_input};
+use iced::{Alignment, Element, Length};
+
+/// The default prefix length of the subnet panel.
+const DEFAULT_PREFIX: u8 = 24;
+
+pub(crate) struct NetworkPanel {
+    /// The prefix length of the subnet, 0 to 32.
+    prefix: u8,
+    /// The text of the address input while it is edited.
+    input: String,
+}
+
+impl<'a> NetworkPanel {
+    pub fn new() -> Self {
+        Self {
+            prefix: DEFAULT_PREFIX,
+            input: String::new(),
+        }
+    }
+
+    pub fn update(&mut self, msg: NetworkMessage, value: &mut u32) {
+        match msg {
+            NetworkMessage::AddressChanged(input) => {
+                if let Some((address, prefix)) = parse_cidr(&input) {
+                    *value = address;
+                    if let Some(prefix) = prefix {
+                        self.prefix = prefix;
+                    }
+                }
+                self.input = input;
+            }
+            NetworkMessage::PrefixChanged(prefix) => self.prefix = prefix,
+        }
+    }
+
+    pub fn view(&self, value: u32) -> Element<'a, NetworkMessage> {
+        let subnet = Subnet::new(value, self.prefix);
+        // Keep the text being typed as long as it is incomplete or still
+        // describes the current value, otherwise show the current value.
+        let address = match parse_cidr(&self.input) {
+            Some((address, prefix))
+                if address == value && prefix.is_none_or(|p| p == self.prefix) =>
+            {
+                self.input.clone()
+            }
+            None if !self.input.is_empty() => self.input.clone(),
+            _ => format!("{}/{}", Ipv4Addr::from(value), self.prefix),
+        };
+        let address_input = text_input("192.168.0.1/24", &address, NetworkMessage::AddressChanged)
+            .width(Length::Units(200));
+        let prefix_slider =
+            slider(0..=32, self.prefix, NetworkMessage::PrefixChanged).width(Length::Units(300));
+        let hosts = subnet.hosts();
+        column![
+            row![text(tr("ipv4")), address_input]
+                .spacing(10)
+                .align_items(Alignment::Center),
+            row![text(format!("/{}", self.prefix)), prefix_slider]
+                .spacing(10)
+                .align_items(Alignment::Center),
+            row![
+                column![
+                    text(tr("netmask")),
+                    text(tr("wildcard_mask")),
+                    text(tr("network")),
+                    text(tr("broadcast")),
+                    text(tr("host_range")),
+                    text(tr("hosts")),
+                ]
+                .spacing(5),
+                column![
+                    text(Ipv4Addr::from(subnet.mask())),
+                    text(Ipv4Addr::from(subnet.wildcard())),
+                    text(Ipv4Addr::from(subnet.network())),
+                    text(Ipv4Addr::from(subnet.broadcast())),
+                    text(format!(
+                        "{} - {}",
+                        Ipv4Addr::from(subnet.first_host()),
+                        Ipv4Addr::from(subnet.last_host())
+                    )),
+                    text(hosts),
+                ]
+                .spacing(5),
+            ]
+            .spacing(20),
+        ]
+        .spacing(10)
+        .into()
+    }
+
+    pub(crate) fn prefix(&self) -> u8 {
+        self.prefix
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum NetworkMessage {
+    AddressChanged(String),
+    PrefixChanged(u8),
+}
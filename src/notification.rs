@@ -0,0 +1,58 @@
+use iced::widget::{button, container, row, text};
+use iced::{theme, Alignment, Element, Length, Theme};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NotificationKind {
+    Success,
+    Error,
+}
+
+/// A short message about the result of an action, e.g. a copy, shown at the
+/// bottom of the window until it is dismissed or replaced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Notification {
+    pub kind: NotificationKind,
+    pub text: String,
+}
+
+impl<'a> Notification {
+    pub fn success(text: impl Into<String>) -> Self {
+        Self {
+            kind: NotificationKind::Success,
+            text: text.into(),
+        }
+    }
+
+    pub fn error(text: impl Into<String>) -> Self {
+        Self {
+            kind: NotificationKind::Error,
+            text: text.into(),
+        }
+    }
+
+    /// Shows the notification with a button which sends `on_dismiss`.
+    pub fn view<Message: Clone + 'a>(
+        &self,
+        theme: &Theme,
+        on_dismiss: Message,
+    ) -> Element<'a, Message> {
+        let palette = theme.palette();
+        let color = match self.kind {
+            NotificationKind::Success => palette.success,
+            NotificationKind::Error => palette.danger,
+        };
+        container(
+            row![
+                text(&self.text)
+                    .style(theme::Text::Color(color))
+                    .width(Length::Fill),
+                button("x").on_press(on_dismiss),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center),
+        )
+        .padding(5)
+        .style(theme::Container::Box)
+        .into()
+    }
+}
@@ -0,0 +1,35 @@
+use num_format::Locale;
+
+use crate::translations::tr;
+
+/// The locale which groups the digits of decimal numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum NumberLocale {
+    /// Follow the language of the user interface.
+    #[default]
+    Language,
+    Named(Locale),
+}
+
+impl NumberLocale {
+    /// Following the language, then every locale known to `num_format`.
+    pub fn all() -> Vec<NumberLocale> {
+        std::iter::once(NumberLocale::Language)
+            .chain(
+                Locale::available_names()
+                    .iter()
+                    .filter_map(|name| Locale::from_name(*name).ok())
+                    .map(NumberLocale::Named),
+            )
+            .collect()
+    }
+}
+
+impl std::fmt::Display for NumberLocale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NumberLocale::Language => write!(f, "{}", tr("follow_language")),
+            NumberLocale::Named(locale) => write!(f, "{}", locale.name()),
+        }
+    }
+}
@@ -233,7 +233,7 @@ where
         viewport: &iced::Rectangle,
     ) {
         self.content.draw(
-            state.children.get(0).unwrap(),
+            state.children.first().unwrap(),
             renderer,
             theme,
             style,
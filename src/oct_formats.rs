@@ -0,0 +1,50 @@
+use crate::format_template::FormatTemplate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OctFormats {
+    #[default]
+    Plain,
+    WithO,
+    WithLeadingZero,
+}
+
+impl OctFormats {
+    pub const ALL: [OctFormats; 3] = [
+        OctFormats::Plain,
+        OctFormats::WithO,
+        OctFormats::WithLeadingZero,
+    ];
+
+    /// The name of the format on the command line, e.g. `oct-0o`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            OctFormats::Plain => "oct",
+            OctFormats::WithO => "oct-0o",
+            OctFormats::WithLeadingZero => "oct-leading-zero",
+        }
+    }
+
+    /// The template which produces this format.
+    pub fn template(&self) -> FormatTemplate {
+        let name = self.to_string();
+        match self {
+            OctFormats::Plain => FormatTemplate::new(&name, 32, "", "").octal(),
+            OctFormats::WithO => FormatTemplate::new(&name, 32, "", "0o").octal(),
+            OctFormats::WithLeadingZero => FormatTemplate::new(&name, 32, "", "0").octal(),
+        }
+    }
+}
+
+impl std::fmt::Display for OctFormats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                OctFormats::Plain => "00004367377",
+                OctFormats::WithO => "0o00004367377",
+                OctFormats::WithLeadingZero => "000004367377",
+            }
+        )
+    }
+}
@@ -0,0 +1,411 @@
+use crate::magnitude;
+
+/// One way to read a pasted text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interpretation {
+    pub value: u32,
+    /// The detected layout, e.g. [`Layout::Hexadecimal`].
+    pub layout: Layout,
+}
+
+/// The layout a pasted text was read in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    VerilogLiteral,
+    VhdlLiteral,
+    MotorolaByteString,
+    IntelByteString,
+    MotorolaByteArray,
+    IntelByteArray,
+    MotorolaBytes,
+    IntelBytes,
+    HexadecimalBlocks,
+    GroupedDecimal,
+    Ipv4Address,
+    Hexadecimal,
+    Binary,
+    Octal,
+    SignedDecimal,
+    Decimal,
+    /// Digits with a leading zero, e.g. `0755`.
+    COctal,
+    /// A decimal with an SI or IEC prefix or an exponent, e.g. `64Ki`.
+    PrefixedDecimal,
+}
+
+impl std::fmt::Display for Layout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Layout::VerilogLiteral => "Verilog literal",
+                Layout::VhdlLiteral => "VHDL literal",
+                Layout::MotorolaByteString => "Motorola byte string",
+                Layout::IntelByteString => "Intel byte string",
+                Layout::MotorolaByteArray => "Motorola byte array",
+                Layout::IntelByteArray => "Intel byte array",
+                Layout::MotorolaBytes => "Motorola bytes",
+                Layout::IntelBytes => "Intel bytes",
+                Layout::HexadecimalBlocks => "hexadecimal blocks",
+                Layout::GroupedDecimal => "grouped decimal",
+                Layout::Ipv4Address => "IPv4 address",
+                Layout::Hexadecimal => "hexadecimal",
+                Layout::Binary => "binary",
+                Layout::Octal => "octal",
+                Layout::SignedDecimal => "signed decimal",
+                Layout::Decimal => "decimal",
+                Layout::COctal => "C octal",
+                Layout::PrefixedDecimal => "prefixed decimal",
+            }
+        )
+    }
+}
+
+impl Interpretation {
+    fn new(value: u32, layout: Layout) -> Self {
+        Self { value, layout }
+    }
+}
+
+/// Reads a text in any of the layouts the calculator can copy: the
+/// `HexFormats` blocks and arrays, `0x`/`0b`/`0o` literals with type
+/// suffixes, Verilog and VHDL literals, byte arrays, Python bytes, grouped
+/// decimals, decimals with SI or IEC prefixes and dotted quads.
+///
+/// Returns every distinct value the text can be read as, the most likely
+/// first. An empty result means the text could not be read at all, more
+/// than one result means it is ambiguous.
+pub fn parse_pasted(input: &str) -> Vec<Interpretation> {
+    let input = input.trim();
+    let mut interpretations = Vec::new();
+    if let Some(interpretation) = parse_hdl_literal(input) {
+        interpretations.push(interpretation);
+    } else if let Some(bytes) = parse_python_bytes(input) {
+        interpretations.extend(from_bytes(
+            &bytes,
+            Layout::MotorolaByteString,
+            Layout::IntelByteString,
+        ));
+    } else if let Some(items) = array_items(input) {
+        if let Some(bytes) = items
+            .iter()
+            .map(|item| parse_array_byte(item))
+            .collect::<Option<Vec<_>>>()
+        {
+            interpretations.extend(from_bytes(
+                &bytes,
+                Layout::MotorolaByteArray,
+                Layout::IntelByteArray,
+            ));
+        }
+    } else if input.split_whitespace().count() > 1 {
+        interpretations.extend(parse_blocks(input));
+    } else {
+        interpretations.extend(parse_decimal_groups(input));
+        interpretations.extend(parse_number(input));
+        interpretations.extend(parse_prefixed(input));
+    }
+    let mut distinct: Vec<Interpretation> = Vec::new();
+    for interpretation in interpretations {
+        if !distinct.iter().any(|i| i.value == interpretation.value) {
+            distinct.push(interpretation);
+        }
+    }
+    distinct
+}
+
+/// Reads Verilog literals like `32'h1234_ABCD` and VHDL literals like `x"1234ABCD"`.
+fn parse_hdl_literal(input: &str) -> Option<Interpretation> {
+    if let Some((_, literal)) = input.split_once('\'') {
+        // `32'sh10` is a signed literal, the bits are the same.
+        let literal = literal.strip_prefix(['s', 'S']).unwrap_or(literal);
+        let mut chars = literal.chars();
+        let radix = match chars.next()?.to_ascii_lowercase() {
+            'h' => 16,
+            'd' => 10,
+            'o' => 8,
+            'b' => 2,
+            _ => return None,
+        };
+        let digits = chars.as_str().replace('_', "");
+        let value = u32::from_str_radix(&digits, radix).ok()?;
+        return Some(Interpretation::new(value, Layout::VerilogLiteral));
+    }
+    let (radix, digits) = match input.get(..2)?.to_ascii_lowercase().as_str() {
+        "x\"" => (16, &input[2..]),
+        "o\"" => (8, &input[2..]),
+        "b\""
+            if input[2..]
+                .trim_end_matches('"')
+                .chars()
+                .all(|c| c == '0' || c == '1') =>
+        {
+            (2, &input[2..])
+        }
+        _ => return None,
+    };
+    let digits = digits.strip_suffix('"')?.replace('_', "");
+    let value = u32::from_str_radix(&digits, radix).ok()?;
+    Some(Interpretation::new(value, Layout::VhdlLiteral))
+}
+
+/// Reads Python byte strings like `b"\x12\x34"`, optionally assigned to a name.
+fn parse_python_bytes(input: &str) -> Option<Vec<u8>> {
+    let input = input.rsplit('=').next()?.trim();
+    let escaped = input
+        .strip_prefix("b\"")
+        .or_else(|| input.strip_prefix("b'"))?;
+    let escaped = escaped
+        .strip_suffix('"')
+        .or_else(|| escaped.strip_suffix('\''))?;
+    escaped
+        .split("\\x")
+        .skip(1)
+        .map(|byte| u8::from_str_radix(byte, 16).ok())
+        .collect()
+}
+
+/// Returns the items of a bracketed array like `[0x00, 0x11]`, `{0x12, 0x34}`
+/// or `uint8_t buf[] = {…};`.
+fn array_items(input: &str) -> Option<Vec<&str>> {
+    let input = input.trim_end_matches(';').trim_end();
+    let close = input.chars().last().filter(|c| *c == ']' || *c == '}')?;
+    let open = if close == ']' { '[' } else { '{' };
+    let start = input.rfind(open)?;
+    let items = &input[start + 1..input.len() - 1];
+    if !items.contains(',') && input.contains(' ') {
+        // `[0x00] [0x11] …` are bracketed blocks, not an array.
+        return None;
+    }
+    Some(
+        items
+            .split(',')
+            .map(str::trim)
+            .filter(|i| !i.is_empty())
+            .collect(),
+    )
+}
+
+/// Reads one byte of an array: `0xab`, `(byte) 0xab`, `171` or `0xab_u8`.
+fn parse_array_byte(item: &str) -> Option<u8> {
+    let item = item.trim_start_matches("(byte)").trim();
+    let item = strip_type_suffix(item);
+    let value = match item.get(..2).map(str::to_ascii_lowercase).as_deref() {
+        Some("0x") => u32::from_str_radix(&item[2..], 16).ok()?,
+        Some("0b") => u32::from_str_radix(&item[2..], 2).ok()?,
+        Some("0o") => u32::from_str_radix(&item[2..], 8).ok()?,
+        _ => item.parse().ok()?,
+    };
+    u8::try_from(value).ok()
+}
+
+/// Reads blocks separated by white space like `0011 eeff`, `00 11 ee ff`,
+/// `0x00 0x11 0xee 0xff` or `[0x00] [0x11] [0xee] [0xff]`.
+fn parse_blocks(input: &str) -> Vec<Interpretation> {
+    let blocks: Option<Vec<(u32, usize)>> = input
+        .split_whitespace()
+        .map(|block| {
+            let block = block.trim_start_matches('[').trim_end_matches(']');
+            let digits = block
+                .strip_prefix("0x")
+                .or_else(|| block.strip_prefix("0X"))
+                .unwrap_or(block);
+            if digits.is_empty() || digits.len() > 8 {
+                return None;
+            }
+            let value = u32::from_str_radix(digits, 16).ok()?;
+            Some((value, digits.len()))
+        })
+        .collect();
+    let Some(blocks) = blocks else {
+        return Vec::new();
+    };
+    let digits = blocks[0].1;
+    if blocks.iter().any(|(_, d)| *d != digits) || digits * blocks.len() > 8 {
+        return Vec::new();
+    }
+    if digits == 2 {
+        let bytes: Vec<u8> = blocks.iter().map(|(value, _)| *value as u8).collect();
+        return from_bytes(&bytes, Layout::MotorolaBytes, Layout::IntelBytes);
+    }
+    let value = blocks.iter().fold(0_u64, |value, (block, _)| {
+        (value << (digits * 4)) | *block as u64
+    });
+    vec![Interpretation::new(value as u32, Layout::HexadecimalBlocks)]
+}
+
+/// Combines bytes most significant first (Motorola) and, if there are
+/// several, least significant first (Intel).
+fn from_bytes(bytes: &[u8], motorola_layout: Layout, intel_layout: Layout) -> Vec<Interpretation> {
+    if bytes.is_empty() || bytes.len() > 4 {
+        return Vec::new();
+    }
+    let motorola = bytes
+        .iter()
+        .fold(0_u32, |value, byte| (value << 8) | *byte as u32);
+    let intel = bytes
+        .iter()
+        .rev()
+        .fold(0_u32, |value, byte| (value << 8) | *byte as u32);
+    let mut interpretations = vec![Interpretation::new(motorola, motorola_layout)];
+    if bytes.len() > 1 {
+        interpretations.push(Interpretation::new(intel, intel_layout));
+    }
+    interpretations
+}
+
+/// Reads decimals grouped by `.` or `,` like `1.234.567` and dotted quads
+/// like `192.168.0.1`.
+fn parse_decimal_groups(input: &str) -> Vec<Interpretation> {
+    let mut interpretations = Vec::new();
+    for separator in ['.', ','] {
+        let groups: Vec<&str> = input.split(separator).collect();
+        if groups.len() < 2 || !groups.iter().all(|g| !g.is_empty() && is_decimal(g)) {
+            continue;
+        }
+        let grouped = groups[0].len() <= 3 && groups[1..].iter().all(|g| g.len() == 3);
+        if grouped {
+            if let Ok(value) = groups.concat().parse() {
+                interpretations.push(Interpretation::new(value, Layout::GroupedDecimal));
+            }
+        }
+        if separator == '.' {
+            if let Ok(address) = input.parse::<std::net::Ipv4Addr>() {
+                interpretations.push(Interpretation::new(address.into(), Layout::Ipv4Address));
+            }
+        }
+    }
+    interpretations
+}
+
+/// Reads a single number: `0x`, `0b` and `0o` literals with underscores and
+/// type suffixes like `u`, `UL` or `_u32`, negative decimals, and plain
+/// digits, which can be decimal, hexadecimal or, with a leading zero, octal.
+fn parse_number(input: &str) -> Vec<Interpretation> {
+    let number = strip_type_suffix(input).replace('_', "");
+    let prefixed = |prefix: &str| {
+        number
+            .strip_prefix(prefix)
+            .or_else(|| number.strip_prefix(&prefix.to_ascii_uppercase()))
+    };
+    if let Some(digits) = prefixed("0x") {
+        return parse_radix(digits, 16, Layout::Hexadecimal);
+    }
+    if let Some(digits) = prefixed("0b") {
+        return parse_radix(digits, 2, Layout::Binary);
+    }
+    if let Some(digits) = prefixed("0o") {
+        return parse_radix(digits, 8, Layout::Octal);
+    }
+    if let Some(digits) = number.strip_prefix('-') {
+        return match digits.parse::<u32>() {
+            Ok(value) if value <= 1 << 31 => vec![Interpretation::new(
+                (value as i32).wrapping_neg() as u32,
+                Layout::SignedDecimal,
+            )],
+            _ => Vec::new(),
+        };
+    }
+    let mut interpretations = Vec::new();
+    if is_decimal(&number) {
+        interpretations.extend(parse_radix(&number, 10, Layout::Decimal));
+        if number.len() > 1 && number.starts_with('0') {
+            interpretations.extend(parse_radix(&number, 8, Layout::COctal));
+        }
+    }
+    interpretations.extend(parse_radix(&number, 16, Layout::Hexadecimal));
+    interpretations
+}
+
+/// Reads decimals with an SI or IEC prefix or an exponent like `64Ki`,
+/// `1.5M` or `1.5e6`. Texts which are hexadecimal numbers, like `1e6`, are
+/// left to [`parse_number`].
+fn parse_prefixed(input: &str) -> Vec<Interpretation> {
+    if input.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Vec::new();
+    }
+    magnitude::parse_magnitude(input)
+        .map(|value| vec![Interpretation::new(value, Layout::PrefixedDecimal)])
+        .unwrap_or_default()
+}
+
+fn parse_radix(digits: &str, radix: u32, layout: Layout) -> Vec<Interpretation> {
+    u32::from_str_radix(digits, radix)
+        .map(|value| vec![Interpretation::new(value, layout)])
+        .unwrap_or_default()
+}
+
+/// Removes C and Rust integer type suffixes like `u`, `UL` or `_u32`.
+fn strip_type_suffix(input: &str) -> &str {
+    let lower = input.to_ascii_lowercase();
+    let hex = lower.starts_with("0x");
+    for suffix in [
+        "_u32", "_i32", "_u8", "u32", "i32", "u8", "ull", "ul", "ll", "u", "l",
+    ] {
+        // `0x…u8` is a suffix, but a `b` or `d` of a hex number is a digit.
+        if lower.ends_with(suffix) && lower.len() > suffix.len() + usize::from(hex) * 2 {
+            return &input[..input.len() - suffix.len()];
+        }
+    }
+    input
+}
+
+fn is_decimal(input: &str) -> bool {
+    !input.is_empty() && input.chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(input: &str) -> Vec<u32> {
+        parse_pasted(input).iter().map(|i| i.value).collect()
+    }
+
+    #[test]
+    fn verilog_literals() {
+        assert_eq!(values("32'h1234_ABCD"), [0x1234_abcd]);
+        assert_eq!(values("8'b1010"), [0b1010]);
+        assert_eq!(values("32'sh10"), [0x10]);
+        assert_eq!(values("32'Sh10"), [0x10]);
+        assert_eq!(values("32'SH10"), [0x10]);
+        assert!(values("32'ss10").is_empty());
+        assert_eq!(values("x\"1234ABCD\""), [0x1234_abcd]);
+        assert_eq!(values("b\"1010\""), [0b1010]);
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(values("0x1F"), [0x1f]);
+        assert_eq!(values("0xffu"), [0xff]);
+        assert_eq!(values("0b1010_1010"), [0xaa]);
+        assert_eq!(values("-3"), [0xffff_fffd]);
+        assert_eq!(values("10"), [10, 0x10]);
+        assert_eq!(values("010"), [10, 0o10, 0x10]);
+        assert_eq!(values("64Ki"), [0x10000]);
+        assert!(values("hello").is_empty());
+        assert_eq!(parse_pasted("0x1F")[0].layout, Layout::Hexadecimal);
+    }
+
+    #[test]
+    fn bytes_and_blocks() {
+        assert_eq!(values("0011 eeff"), [0x0011_eeff]);
+        assert_eq!(values("00 11 ee ff"), [0x0011_eeff, 0xffee_1100]);
+        assert_eq!(values("{0x12, 0x34}"), [0x1234, 0x3412]);
+        assert_eq!(values("uint8_t buf[] = {0x12, 0x34};"), [0x1234, 0x3412]);
+        assert_eq!(values("b\"\\x12\\x34\""), [0x1234, 0x3412]);
+        assert_eq!(
+            parse_pasted("[0x12, 0x34]")[1].layout,
+            Layout::IntelByteArray
+        );
+    }
+
+    #[test]
+    fn grouped_decimals_and_addresses() {
+        assert_eq!(values("1.234.567"), [1_234_567]);
+        assert_eq!(values("1,234,567"), [1_234_567]);
+        assert_eq!(values("192.168.0.1"), [0xc0a8_0001]);
+        assert_eq!(parse_pasted("192.168.0.1")[0].layout, Layout::Ipv4Address);
+    }
+}
@@ -0,0 +1,100 @@
+pub const SETUID: u32 = 0o4000;
+pub const SETGID: u32 = 0o2000;
+pub const STICKY: u32 = 0o1000;
+
+/// Formats the low 12 bits as in `ls -l`, e.g. `rwsr-x--T`.
+pub fn to_symbolic(value: u32) -> String {
+    let special = [(SETUID, 's'), (SETGID, 's'), (STICKY, 't')];
+    [0o700, 0o070, 0o007]
+        .iter()
+        .zip(special)
+        .flat_map(|(mask, (special_bit, special_char))| {
+            let read = if value & 0o444 & mask != 0 { 'r' } else { '-' };
+            let write = if value & 0o222 & mask != 0 { 'w' } else { '-' };
+            let execute = match (value & 0o111 & mask != 0, value & special_bit != 0) {
+                (true, true) => special_char,
+                (false, true) => special_char.to_ascii_uppercase(),
+                (true, false) => 'x',
+                (false, false) => '-',
+            };
+            [read, write, execute]
+        })
+        .collect()
+}
+
+/// Applies a symbolic mode like `chmod` does, e.g. `u+x,go-w` or `a=rX`,
+/// to the low 12 bits of `value`. Returns `None` if the mode is invalid.
+pub fn apply_symbolic(value: u32, mode: &str) -> Option<u32> {
+    let mut value = value;
+    for clause in mode.split(',') {
+        let clause = clause.trim();
+        let op_start = clause.find(['+', '-', '='])?;
+        let (who, mut actions) = clause.split_at(op_start);
+        let mut who_mask = 0;
+        for c in who.chars() {
+            who_mask |= match c {
+                'u' => 0o700 | SETUID,
+                'g' => 0o070 | SETGID,
+                'o' => 0o007 | STICKY,
+                'a' => 0o777 | SETUID | SETGID | STICKY,
+                _ => return None,
+            };
+        }
+        if who_mask == 0 {
+            who_mask = 0o777 | SETUID | SETGID | STICKY;
+        }
+        while let Some(op) = actions.chars().next() {
+            actions = &actions[1..];
+            let end = actions.find(['+', '-', '=']).unwrap_or(actions.len());
+            let (perms, rest) = actions.split_at(end);
+            actions = rest;
+            let mut bits = 0;
+            for c in perms.chars() {
+                bits |= match c {
+                    'r' => 0o444,
+                    'w' => 0o222,
+                    'x' => 0o111,
+                    'X' if value & 0o111 != 0 => 0o111,
+                    'X' => 0,
+                    's' => SETUID | SETGID,
+                    't' => STICKY,
+                    'u' => copy_class(value, 6),
+                    'g' => copy_class(value, 3),
+                    'o' => copy_class(value, 0),
+                    _ => return None,
+                };
+            }
+            bits &= who_mask;
+            match op {
+                '+' => value |= bits,
+                '-' => value &= !bits,
+                _ => value = (value & !who_mask) | bits,
+            }
+        }
+    }
+    Some(value)
+}
+
+/// Spreads the rwx bits of the class at `shift` to all three classes.
+fn copy_class(value: u32, shift: u32) -> u32 {
+    ((value >> shift) & 0o7) * 0o111
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symbolic_modes() {
+        assert_eq!(apply_symbolic(0o644, "u+x,go-w"), Some(0o744));
+        assert_eq!(apply_symbolic(0o644, "a=rX"), Some(0o444));
+        assert_eq!(apply_symbolic(0o755, "a=rX"), Some(0o555));
+        assert_eq!(apply_symbolic(0o600, "g=u"), Some(0o660));
+        assert_eq!(apply_symbolic(0o755, "u+s"), Some(0o4755));
+        assert_eq!(apply_symbolic(0o644, "+x"), Some(0o755));
+        assert_eq!(apply_symbolic(0o644, "z+x"), None);
+        assert_eq!(apply_symbolic(0o644, "u"), None);
+        assert_eq!(to_symbolic(0o4751), "rwsr-x--x");
+        assert_eq!(to_symbolic(0o1644), "rw-r--r-T");
+    }
+}
@@ -0,0 +1,111 @@
+use crate::translations::tr;
+use binary_calculator::permission_modes::{apply_symbolic, to_symbolic, SETGID, SETUID, STICKY};
+use iced::widget::{checkbox, column, row, text, text_input, Column};
+use iced::{Alignment, Element, Length};
+
+/// The translation keys and permission bits of the user, group and other
+/// classes, highest first.
+const CLASSES: [(&str, u32); 3] = [("user", 0o700), ("group", 0o070), ("other", 0o007)];
+
+pub(crate) struct PermissionsPanel {
+    /// The symbolic mode being typed, e.g. `u+x,go-w`.
+    input: String,
+    /// Whether the last applied symbolic mode could not be parsed.
+    invalid: bool,
+}
+
+impl<'a> PermissionsPanel {
+    pub fn new() -> Self {
+        Self {
+            input: String::new(),
+            invalid: false,
+        }
+    }
+
+    pub fn update(&mut self, msg: PermissionsMessage, value: &mut u32) {
+        match msg {
+            PermissionsMessage::Toggled(bit, set) => {
+                if set {
+                    *value |= bit;
+                } else {
+                    *value &= !bit;
+                }
+            }
+            PermissionsMessage::SymbolicChanged(input) => {
+                self.input = input;
+                self.invalid = false;
+            }
+            PermissionsMessage::SymbolicSubmitted => match apply_symbolic(*value, &self.input) {
+                Some(new_value) => {
+                    *value = new_value;
+                    self.input.clear();
+                }
+                None => self.invalid = true,
+            },
+        }
+    }
+
+    pub fn view(&self, value: u32) -> Element<'a, PermissionsMessage> {
+        let header = row![
+            text("").width(Length::Units(60)),
+            text("r").width(Length::Units(30)),
+            text("w").width(Length::Units(30)),
+            text("x").width(Length::Units(30)),
+        ];
+        let grid = CLASSES.iter().fold(
+            Column::new().spacing(5).push(header),
+            |column, (name, mask)| {
+                let bits = [0o444 & mask, 0o222 & mask, 0o111 & mask];
+                column.push(bits.iter().fold(
+                    row![text(tr(name)).width(Length::Units(60))],
+                    |row, bit| {
+                        let bit = *bit;
+                        row.push(
+                            checkbox("", value & bit != 0, move |set| {
+                                PermissionsMessage::Toggled(bit, set)
+                            })
+                            .width(Length::Units(30)),
+                        )
+                    },
+                ))
+            },
+        );
+        let special = [("setuid", SETUID), ("setgid", SETGID), ("sticky", STICKY)]
+            .iter()
+            .fold(row![].spacing(20), |row, (name, bit)| {
+                let bit = *bit;
+                row.push(checkbox(*name, value & bit != 0, move |set| {
+                    PermissionsMessage::Toggled(bit, set)
+                }))
+            });
+        let symbolic_input =
+            text_input("u+x,go-w", &self.input, PermissionsMessage::SymbolicChanged)
+                .on_submit(PermissionsMessage::SymbolicSubmitted)
+                .width(Length::Units(200));
+        let mut symbolic_row = row![symbolic_input]
+            .spacing(10)
+            .align_items(Alignment::Center);
+        if self.invalid {
+            symbolic_row = symbolic_row.push(text(tr("invalid_mode")));
+        }
+        column![
+            row![
+                text(to_symbolic(value)).size(30),
+                text(format!("{:04o}", value & 0o7777)).size(30),
+            ]
+            .spacing(20),
+            grid,
+            special,
+            symbolic_row,
+        ]
+        .spacing(10)
+        .into()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum PermissionsMessage {
+    Toggled(u32, bool),
+    SymbolicChanged(String),
+    SymbolicSubmitted,
+}
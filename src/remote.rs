@@ -0,0 +1,358 @@
+// Without Unix sockets only the types of the calls are used.
+#![cfg_attr(not(unix), allow(dead_code))]
+
+use std::sync::mpsc;
+
+use iced::Subscription;
+use serde::Deserialize;
+use serde_json::{json, Value as Json};
+
+use crate::messages::Message;
+use binary_calculator::value::Operation;
+
+/// The JSON-RPC error codes.
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// A call of a remote client, answered by the calculator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Call {
+    /// Reads the value and sign mode.
+    GetValue,
+    /// Replaces the value.
+    SetValue(u32),
+    /// Changes the value like the keys of the calculator.
+    Apply(Operation),
+    SetSigned(bool),
+    /// Formats the value with the copy format of the name, e.g.
+    /// `intel-array`, or as decimal with `dec`.
+    Convert(String),
+}
+
+/// A call waiting for its answer. The answer is the `result` or the `error`
+/// message of the JSON-RPC response.
+#[derive(Debug, Clone)]
+pub(crate) struct RemoteCall {
+    pub call: Call,
+    reply: mpsc::Sender<Result<Json, String>>,
+}
+
+impl RemoteCall {
+    pub fn reply(&self, result: Result<Json, String>) {
+        // The client may have gone in the meantime.
+        let _ = self.reply.send(result);
+    }
+}
+
+#[derive(Deserialize)]
+struct Request {
+    #[serde(default)]
+    id: Json,
+    method: String,
+    #[serde(default)]
+    params: Json,
+}
+
+#[derive(Deserialize)]
+struct ValueParams {
+    /// A number, or a text in any layout the calculator can paste.
+    value: Json,
+}
+
+#[derive(Deserialize)]
+struct SignedParams {
+    signed: bool,
+}
+
+#[derive(Deserialize)]
+struct ConvertParams {
+    format: String,
+}
+
+/// Reads the call of a request, or the error code and message.
+fn parse_call(request: &Request) -> Result<Call, (i64, String)> {
+    fn params<T: for<'de> Deserialize<'de>>(params: &Json) -> Result<T, (i64, String)> {
+        serde_json::from_value(params.clone()).map_err(|err| (INVALID_PARAMS, err.to_string()))
+    }
+    match request.method.as_str() {
+        "get_value" => Ok(Call::GetValue),
+        "set_value" => match params::<ValueParams>(&request.params)?.value {
+            Json::Number(number) => number
+                .as_u64()
+                .and_then(|value| u32::try_from(value).ok())
+                .or_else(|| {
+                    number
+                        .as_i64()
+                        .and_then(|value| i32::try_from(value).ok())
+                        .map(|value| value as u32)
+                })
+                .map(Call::SetValue)
+                .ok_or((
+                    INVALID_PARAMS,
+                    format!("{number} does not fit into 32 bits"),
+                )),
+            Json::String(text) => binary_calculator::paste_parser::parse_pasted(&text)
+                .first()
+                .map(|interpretation| Call::SetValue(interpretation.value))
+                .ok_or((INVALID_PARAMS, format!("cannot read `{text}` as a number"))),
+            _ => Err((
+                INVALID_PARAMS,
+                "the value must be a number or text".to_string(),
+            )),
+        },
+        "apply" => Ok(Call::Apply(params(&request.params)?)),
+        "set_signed" => Ok(Call::SetSigned(
+            params::<SignedParams>(&request.params)?.signed,
+        )),
+        "convert" => Ok(Call::Convert(
+            params::<ConvertParams>(&request.params)?.format,
+        )),
+        method => Err((METHOD_NOT_FOUND, format!("unknown method `{method}`"))),
+    }
+}
+
+/// Answers one line of a client: a JSON-RPC request, which is sent to the
+/// calculator through `calls` unless it is invalid. A request without `id`
+/// is a notification, which is carried out but not answered.
+fn answer(line: &str, calls: &impl Fn(RemoteCall) -> bool) -> Option<String> {
+    let error = |id: &Json, code: i64, message: String| {
+        json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": {"code": code, "message": message},
+        })
+    };
+    let response = match serde_json::from_str::<Json>(line) {
+        Err(err) => error(&Json::Null, PARSE_ERROR, err.to_string()),
+        Ok(request) => {
+            let notification = request.get("id").is_none();
+            let response = match serde_json::from_value::<Request>(request) {
+                Err(err) => error(&Json::Null, INVALID_REQUEST, err.to_string()),
+                Ok(request) => match parse_call(&request) {
+                    Err((code, message)) => error(&request.id, code, message),
+                    Ok(call) => {
+                        let (reply, answer) = mpsc::channel();
+                        let answer = if calls(RemoteCall { call, reply }) {
+                            answer.recv().ok()
+                        } else {
+                            None
+                        };
+                        match answer {
+                            Some(Ok(result)) => {
+                                json!({"jsonrpc": "2.0", "id": request.id, "result": result})
+                            }
+                            Some(Err(message)) => error(&request.id, INVALID_PARAMS, message),
+                            None => error(
+                                &request.id,
+                                INVALID_REQUEST,
+                                "the calculator is closing".to_string(),
+                            ),
+                        }
+                    }
+                },
+            };
+            if notification {
+                return None;
+            }
+            response
+        }
+    };
+    Some(response.to_string())
+}
+
+/// Serves the socket in the runtime directory while the calculator runs:
+/// each line a client writes is a JSON-RPC 2.0 request, answered by one line,
+/// e.g. `{"jsonrpc": "2.0", "id": 1, "method": "apply", "params": {"shift_left": 4}}`.
+#[cfg(unix)]
+pub(crate) fn subscription() -> Subscription<Message> {
+    use iced::futures::channel::mpsc::{unbounded, UnboundedReceiver};
+    use iced::futures::StreamExt;
+
+    enum State {
+        Starting,
+        Serving(UnboundedReceiver<RemoteCall>),
+        Stopped,
+    }
+
+    struct Remote;
+
+    iced::subscription::unfold(
+        std::any::TypeId::of::<Remote>(),
+        State::Starting,
+        |state| async move {
+            match state {
+                State::Starting => {
+                    let (sender, receiver) = unbounded();
+                    match server::start(move |call| sender.unbounded_send(call).is_ok()) {
+                        Ok(()) => (None, State::Serving(receiver)),
+                        Err(err) => {
+                            eprintln!("{err}");
+                            (None, State::Stopped)
+                        }
+                    }
+                }
+                State::Serving(mut receiver) => match receiver.next().await {
+                    Some(call) => (Some(Message::Remote(call)), State::Serving(receiver)),
+                    None => (None, State::Stopped),
+                },
+                State::Stopped => iced::futures::future::pending().await,
+            }
+        },
+    )
+}
+
+#[cfg(not(unix))]
+pub(crate) fn subscription() -> Subscription<Message> {
+    Subscription::none()
+}
+
+/// Removes the socket when the calculator closes.
+#[cfg(unix)]
+pub(crate) fn close() {
+    server::close();
+}
+
+#[cfg(not(unix))]
+pub(crate) fn close() {}
+
+#[cfg(unix)]
+mod server {
+    use std::fs;
+    use std::io::{self, BufRead, BufReader, Write};
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::PathBuf;
+    use std::sync::{Arc, OnceLock};
+
+    use super::{answer, RemoteCall};
+
+    const SOCKET_NAME: &str = "binary_calculator.sock";
+
+    /// The socket this calculator listens on.
+    static SOCKET: OnceLock<PathBuf> = OnceLock::new();
+
+    /// `<runtime dir>/binary_calculator.sock`, or in a directory only the
+    /// user may enter in the shared temporary directory if there is no
+    /// runtime directory.
+    fn socket_path() -> Result<PathBuf, String> {
+        if let Some(dir) = dirs::runtime_dir() {
+            return Ok(dir.join(SOCKET_NAME));
+        }
+        let user = std::env::var("USER").unwrap_or_else(|_| "user".to_string());
+        let dir = std::env::temp_dir().join(format!("binary_calculator-{user}"));
+        match fs::DirBuilder::new().mode(0o700).create(&dir) {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+                let private = fs::symlink_metadata(&dir).is_ok_and(|metadata| {
+                    metadata.is_dir() && metadata.permissions().mode() & 0o077 == 0
+                });
+                if !private {
+                    return Err(format!("{} is open to other users", dir.display()));
+                }
+            }
+            Err(err) => return Err(format!("{}: {err}", dir.display())),
+        }
+        Ok(dir.join(SOCKET_NAME))
+    }
+
+    /// Listens on the socket in a thread, with a thread per client. Fails if
+    /// another calculator already listens.
+    pub(super) fn start(
+        calls: impl Fn(RemoteCall) -> bool + Send + Sync + 'static,
+    ) -> Result<(), String> {
+        let path = socket_path()?;
+        if UnixStream::connect(&path).is_ok() {
+            return Err(format!(
+                "{} is served by another calculator",
+                path.display()
+            ));
+        }
+        // Left over by a calculator which did not close.
+        let _ = fs::remove_file(&path);
+        let listener =
+            UnixListener::bind(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600))
+            .map_err(|err| format!("{}: {err}", path.display()))?;
+        let _ = SOCKET.set(path);
+        let calls = Arc::new(calls);
+        std::thread::spawn(move || {
+            for client in listener.incoming().flatten() {
+                let calls = calls.clone();
+                std::thread::spawn(move || serve(client, &*calls));
+            }
+        });
+        Ok(())
+    }
+
+    /// Removes the socket, if this calculator listens on it.
+    pub(super) fn close() {
+        if let Some(path) = SOCKET.get() {
+            let _ = fs::remove_file(path);
+        }
+    }
+
+    fn serve(client: UnixStream, calls: &impl Fn(RemoteCall) -> bool) {
+        let Ok(mut writer) = client.try_clone() else {
+            return;
+        };
+        for line in BufReader::new(client).lines() {
+            let Ok(line) = line else {
+                return;
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Some(response) = answer(&line, calls) {
+                if writeln!(writer, "{response}").is_err() {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Answers every call with the call itself, as text.
+    fn echo(remote: RemoteCall) -> bool {
+        remote.reply(Ok(format!("{:?}", remote.call).into()));
+        true
+    }
+
+    fn respond(line: &str) -> Option<Json> {
+        answer(line, &echo).map(|response| serde_json::from_str(&response).unwrap())
+    }
+
+    #[test]
+    fn requests_are_answered() {
+        let response = respond(
+            r#"{"jsonrpc": "2.0", "id": 1, "method": "apply", "params": {"shift_left": 4}}"#,
+        )
+        .unwrap();
+        assert_eq!(response["id"], 1);
+        assert_eq!(response["result"], "Apply(ShiftLeft(4))");
+        let response = respond(r#"{"jsonrpc": "2.0", "id": null, "method": "get_value"}"#);
+        assert_eq!(response.unwrap()["result"], "GetValue");
+    }
+
+    #[test]
+    fn notifications_are_not_answered() {
+        assert_eq!(
+            respond(r#"{"jsonrpc": "2.0", "method": "get_value"}"#),
+            None
+        );
+        assert_eq!(respond(r#"{"jsonrpc": "2.0", "method": "unknown"}"#), None);
+    }
+
+    #[test]
+    fn errors_are_answered() {
+        assert_eq!(respond("{").unwrap()["error"]["code"], PARSE_ERROR);
+        let response = respond(r#"{"jsonrpc": "2.0", "id": 2, "method": "unknown"}"#).unwrap();
+        assert_eq!(response["error"]["code"], METHOD_NOT_FOUND);
+        assert_eq!(response["id"], 2);
+    }
+}
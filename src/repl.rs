@@ -0,0 +1,321 @@
+use std::path::PathBuf;
+
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+
+use crate::config::Config;
+use binary_calculator::copy_format::CopyFormat;
+use binary_calculator::format_template::FormatTemplate;
+use binary_calculator::paste_parser::parse_pasted;
+use binary_calculator::value::{Operation, Value, MAX_WIDTH};
+
+const HELP: &str = "\
+  <value>            set the value, e.g. 0xff, -3, 64Ki or { 0x12, 0x34 }
+  << n, >> n         shift left or right
+  rol n, ror n       rotate left or right
+  not                invert all bits
+  and, or, xor <v>   combine with a value
+  set, clear, toggle <bit>
+                     change a single bit
+  fmt <format>       print the 32 bit word in a copy format, e.g. fmt c
+  formats            list the copy formats
+  width <bits>       change the word width
+  signed, unsigned   read the word as signed or unsigned
+  bits               print the bit ruler
+  help               print this help
+  quit               leave";
+
+/// A command of the REPL.
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Apply(Operation),
+    Format(String),
+    Formats,
+    Width(u32),
+    Signed(bool),
+    Bits,
+    Help,
+    Quit,
+}
+
+/// Reads commands from the terminal until `quit` or end of input, starting
+/// with `value`. The line history is kept in the data directory.
+pub(crate) fn run(value: Value) -> i32 {
+    let mut editor = match DefaultEditor::new() {
+        Ok(editor) => editor,
+        Err(err) => {
+            eprintln!("cannot open the terminal: {err}");
+            return 1;
+        }
+    };
+    let history = history_path();
+    if let Some(history) = &history {
+        // There is no history before the first session.
+        let _ = editor.load_history(history);
+    }
+    let templates = Config::load_read_only().copy_templates;
+
+    let mut value = value;
+    println!("{}", ruler(value));
+    loop {
+        let line = match editor.readline("> ") {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted | ReadlineError::Eof) => break,
+            Err(err) => {
+                eprintln!("{err}");
+                break;
+            }
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let _ = editor.add_history_entry(line);
+        match parse_command(line) {
+            Ok(Command::Quit) => break,
+            Ok(command) => match execute(command, &mut value, &templates) {
+                Ok(output) => println!("{output}"),
+                Err(err) => eprintln!("{err}"),
+            },
+            Err(err) => eprintln!("{err}, see `help`"),
+        }
+    }
+
+    if let Some(history) = &history {
+        if let Some(directory) = history.parent() {
+            let _ = std::fs::create_dir_all(directory);
+        }
+        if let Err(err) = editor.save_history(history) {
+            eprintln!("cannot save the history: {err}");
+        }
+    }
+    0
+}
+
+/// `<data dir>/binary_calculator/history.txt`
+fn history_path() -> Option<PathBuf> {
+    Some(
+        dirs::data_dir()?
+            .join("binary_calculator")
+            .join("history.txt"),
+    )
+}
+
+/// Carries out a command other than `quit` and returns the text to print,
+/// mostly the ruler of the changed value. Widening a signed word sign
+/// extends it, as in the window.
+fn execute(
+    command: Command,
+    value: &mut Value,
+    templates: &[FormatTemplate],
+) -> Result<String, String> {
+    match command {
+        Command::Apply(operation) => *value = value.apply(operation),
+        Command::Format(name) => {
+            let format = CopyFormat::from_name(&name, templates)
+                .ok_or_else(|| format!("unknown format `{name}`, see `formats`"))?;
+            // The copy formats are layouts of whole 32 bit words.
+            if value.width() != MAX_WIDTH {
+                return Err(format!(
+                    "the copy formats write {MAX_WIDTH} bits, set `width {MAX_WIDTH}` first"
+                ));
+            }
+            return Ok(format.format(value.bits()));
+        }
+        Command::Formats => return Ok(formats(templates)),
+        Command::Width(width) => *value = value.with_width(width),
+        Command::Signed(signed) => *value = value.with_signed(signed),
+        Command::Help => return Ok(HELP.to_string()),
+        Command::Bits | Command::Quit => {}
+    }
+    Ok(ruler(*value))
+}
+
+fn parse_command(line: &str) -> Result<Command, String> {
+    let (word, argument) = match line.split_once(char::is_whitespace) {
+        Some((word, argument)) => (word, argument.trim()),
+        None => (line, ""),
+    };
+    let number = || parse_number(argument);
+    let command = match word {
+        "<<" => Command::Apply(Operation::ShiftLeft(number()?)),
+        ">>" => Command::Apply(Operation::ShiftRight(number()?)),
+        "rol" => Command::Apply(Operation::RotateLeft(number()?)),
+        "ror" => Command::Apply(Operation::RotateRight(number()?)),
+        "not" | "~" => Command::Apply(Operation::Not),
+        "and" | "&" => Command::Apply(Operation::And(number()?)),
+        "or" | "|" => Command::Apply(Operation::Or(number()?)),
+        "xor" | "^" => Command::Apply(Operation::Xor(number()?)),
+        "set" => Command::Apply(Operation::SetBit(number()?, true)),
+        "clear" => Command::Apply(Operation::SetBit(number()?, false)),
+        "toggle" => Command::Apply(Operation::ToggleBit(number()?)),
+        "fmt" if !argument.is_empty() => Command::Format(argument.to_string()),
+        "fmt" => return Err("`fmt` needs a format".to_string()),
+        "formats" => Command::Formats,
+        "width" => match number()? {
+            width @ 1..=MAX_WIDTH => Command::Width(width),
+            _ => return Err(format!("the width must be 1 to {MAX_WIDTH}")),
+        },
+        "signed" => Command::Signed(true),
+        "unsigned" => Command::Signed(false),
+        "bits" => Command::Bits,
+        "help" | "?" => Command::Help,
+        "quit" | "exit" | "q" => Command::Quit,
+        // Operators may be written without a space, e.g. `<<4`.
+        _ => {
+            for operator in ["<<", ">>", "&", "|", "^"] {
+                if let Some(argument) = line.strip_prefix(operator) {
+                    return parse_command(&format!("{operator} {argument}"));
+                }
+            }
+            Command::Apply(Operation::Set(parse_number(line)?))
+        }
+    };
+    Ok(command)
+}
+
+fn parse_number(input: &str) -> Result<u32, String> {
+    if input.is_empty() {
+        return Err("a number is missing".to_string());
+    }
+    parse_pasted(input)
+        .first()
+        .map(|interpretation| interpretation.value)
+        .ok_or_else(|| format!("cannot read `{input}`"))
+}
+
+/// The list of the copy formats, one per line.
+fn formats(templates: &[FormatTemplate]) -> String {
+    [16, 8, 2]
+        .iter()
+        .flat_map(|radix| CopyFormat::all(*radix, templates))
+        .map(|format| format!("  {:<28}{}", format.name(), format))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The bits of the value grouped in nibbles, below the index of the highest
+/// bit of each nibble, followed by the value in hexadecimal and decimal:
+///
+/// ```text
+/// 31   27   23   19   15   11   7    3
+/// 0000 0000 0001 0001 1110 1110 1111 1111
+/// 0x0011eeff = 1175295
+/// ```
+fn ruler(value: Value) -> String {
+    let mut indices = String::new();
+    let mut bits = String::new();
+    for bit in (0..value.width()).rev() {
+        if bit != value.width() - 1 && bit % 4 == 3 {
+            indices.push(' ');
+            bits.push(' ');
+        }
+        if bit == value.width() - 1 || bit % 4 == 3 {
+            // The index takes the place of the bits of its nibble, the
+            // index of a short top nibble is left out if it does not fit.
+            let nibble = (bit % 4 + 1) as usize;
+            let index = bit.to_string();
+            if index.len() <= nibble {
+                indices.push_str(&index);
+                indices.push_str(&" ".repeat(nibble - index.len()));
+            } else {
+                indices.push_str(&" ".repeat(nibble));
+            }
+        }
+        bits.push(if value.bits() >> bit & 1 == 1 {
+            '1'
+        } else {
+            '0'
+        });
+    }
+    format!(
+        "{}\n{bits}\n0x{} = {}",
+        indices.trim_end(),
+        value.to_string_radix(16),
+        value.decimal()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operations() {
+        let shift = Ok(Command::Apply(Operation::ShiftLeft(4)));
+        assert_eq!(parse_command("<< 4"), shift);
+        assert_eq!(parse_command("<<4"), shift);
+        assert_eq!(
+            parse_command("and 0x0f"),
+            Ok(Command::Apply(Operation::And(0x0f)))
+        );
+        assert_eq!(
+            parse_command("&0x0f"),
+            Ok(Command::Apply(Operation::And(0x0f)))
+        );
+        assert_eq!(parse_command("~"), Ok(Command::Apply(Operation::Not)));
+        assert_eq!(
+            parse_command("set 3"),
+            Ok(Command::Apply(Operation::SetBit(3, true)))
+        );
+        assert_eq!(
+            parse_command("0xff"),
+            Ok(Command::Apply(Operation::Set(0xff)))
+        );
+        assert_eq!(
+            parse_command("{ 0x12, 0x34 }"),
+            Ok(Command::Apply(Operation::Set(0x1234)))
+        );
+    }
+
+    #[test]
+    fn commands() {
+        assert_eq!(
+            parse_command("fmt motorola-array"),
+            Ok(Command::Format("motorola-array".to_string()))
+        );
+        assert_eq!(parse_command("width 8"), Ok(Command::Width(8)));
+        assert_eq!(parse_command("unsigned"), Ok(Command::Signed(false)));
+        assert_eq!(parse_command("q"), Ok(Command::Quit));
+    }
+
+    #[test]
+    fn errors() {
+        assert!(parse_command("fmt").is_err());
+        assert!(parse_command("<<").is_err());
+        assert!(parse_command("width 0").is_err());
+        assert!(parse_command("width 33").is_err());
+        assert!(parse_command("bogus").is_err());
+    }
+
+    #[test]
+    fn ruler_of_a_byte() {
+        assert_eq!(
+            ruler(Value::new(0x5a, 8, true)),
+            "7    3\n0101 1010\n0x5a = 90"
+        );
+    }
+
+    #[test]
+    fn width_then_fmt() {
+        let mut value = Value::new(0x1234_5678, 32, false);
+        let mut run = |line: &str| execute(parse_command(line).unwrap(), &mut value, &[]);
+        assert_eq!(run("fmt c"), Ok("0x12345678u".to_string()));
+        assert!(run("width 8").is_ok());
+        assert!(run("fmt c").is_err());
+        assert!(run("width 32").is_ok());
+        assert_eq!(run("fmt c"), Ok("0x00000078u".to_string()));
+    }
+
+    #[test]
+    fn widening_sign_extends() {
+        let mut value = Value::new(0xf0, 8, true);
+        let ruler = execute(Command::Width(16), &mut value, &[]).unwrap();
+        assert_eq!(value, Value::new(0xfff0, 16, true));
+        assert!(ruler.ends_with("0xfff0 = -16"));
+        execute(Command::Width(8), &mut value, &[]).unwrap();
+        execute(Command::Signed(false), &mut value, &[]).unwrap();
+        execute(Command::Width(16), &mut value, &[]).unwrap();
+        assert_eq!(value, Value::new(0xf0, 16, false));
+    }
+}
@@ -0,0 +1,200 @@
+use crate::value::Value;
+
+/// The layouts of the conversion report, which lists the value in every
+/// base and interpretation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReportFormats {
+    #[default]
+    PlainText,
+    Markdown,
+    Json,
+}
+
+impl ReportFormats {
+    pub const ALL: [ReportFormats; 3] = [
+        ReportFormats::PlainText,
+        ReportFormats::Markdown,
+        ReportFormats::Json,
+    ];
+
+    /// Writes the report of the value. `fields` are the named masks whose
+    /// bits are decoded, e.g. the fields of the mask builder.
+    pub fn format<'a>(
+        &self,
+        value: Value,
+        fields: impl IntoIterator<Item = (&'a str, u32)>,
+    ) -> String {
+        let rows = rows(value);
+        let fields = decode_fields(value.bits(), fields);
+        match self {
+            ReportFormats::PlainText => {
+                let rows: Vec<(&str, &str)> = rows
+                    .iter()
+                    .map(|(name, value)| (*name, value.as_str()))
+                    .chain(
+                        fields
+                            .iter()
+                            .map(|(name, value)| (name.as_str(), value.as_str())),
+                    )
+                    .collect();
+                let width = rows.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+                rows.iter()
+                    .map(|(name, value)| format!("{name:width$}  {value}"))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            ReportFormats::Markdown => {
+                std::iter::once("| Interpretation | Value |\n|---|---|".to_string())
+                    .chain(
+                        rows.iter()
+                            .map(|(name, value)| (*name, value.as_str()))
+                            .chain(
+                                fields
+                                    .iter()
+                                    .map(|(name, value)| (name.as_str(), value.as_str())),
+                            )
+                            .map(|(name, value)| {
+                                format!(
+                                    "| {} | {} |",
+                                    markdown_cell(name),
+                                    markdown_cell(&code_span(value))
+                                )
+                            }),
+                    )
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            ReportFormats::Json => {
+                // The fields are nested, so their names cannot clash with
+                // the interpretations.
+                let mut object: serde_json::Map<String, serde_json::Value> = rows
+                    .into_iter()
+                    .map(|(name, value)| (name.to_string(), value.into()))
+                    .collect();
+                object.insert(
+                    "fields".to_string(),
+                    fields
+                        .into_iter()
+                        .map(|(name, value)| (name, value.into()))
+                        .collect::<serde_json::Map<_, _>>()
+                        .into(),
+                );
+                serde_json::to_string_pretty(&object).unwrap_or_default()
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ReportFormats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ReportFormats::PlainText => "Text",
+                ReportFormats::Markdown => "Markdown",
+                ReportFormats::Json => "JSON",
+            }
+        )
+    }
+}
+
+/// The names and texts of the interpretations of the value, in order.
+/// `decimal` is read in the sign mode of the value, the float only exists
+/// for 32 bit words and the chars cover the bytes of the width.
+fn rows(value: Value) -> Vec<(&'static str, String)> {
+    let bytes = value.width().div_ceil(8) as usize;
+    let chars: String = value.bits().to_be_bytes()[4 - bytes..]
+        .iter()
+        .map(|byte| {
+            if byte.is_ascii_graphic() || *byte == b' ' {
+                *byte as char
+            } else {
+                '.'
+            }
+        })
+        .collect();
+    let mut rows = vec![
+        ("hex", format!("0x{}", value.to_string_radix(16))),
+        ("decimal", value.to_string_radix(10)),
+        ("unsigned", value.with_signed(false).decimal().to_string()),
+        ("signed", value.with_signed(true).decimal().to_string()),
+        ("octal", format!("0o{}", value.to_string_radix(8))),
+        ("binary", format!("0b{}", value.to_string_radix(2))),
+    ];
+    if value.width() == 32 {
+        rows.push(("float", f32::from_bits(value.bits()).to_string()));
+    }
+    rows.push(("chars", chars));
+    rows
+}
+
+/// The names and decoded values of the fields.
+fn decode_fields<'a>(
+    value: u32,
+    fields: impl IntoIterator<Item = (&'a str, u32)>,
+) -> Vec<(String, String)> {
+    fields
+        .into_iter()
+        .map(|(name, mask)| {
+            let field = (value & mask) >> mask.trailing_zeros().min(31);
+            (name.to_string(), format!("{field} ({field:#x})"))
+        })
+        .collect()
+}
+
+/// Escapes the pipes which would end a Markdown table cell.
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+/// Puts the text in a Markdown code span, fenced by more backticks than it
+/// contains in a row.
+fn code_span(text: &str) -> String {
+    let longest = text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest + 1);
+    if text.starts_with('`') || text.ends_with('`') {
+        format!("{fence} {text} {fence}")
+    } else {
+        format!("{fence}{text}{fence}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markdown_escapes_pipes_and_backticks() {
+        let report = ReportFormats::Markdown.format(Value::new(0x7c7c_7c7c, 32, false), []);
+        assert!(report.contains("| chars | `\\|\\|\\|\\|` |"));
+        let report = ReportFormats::Markdown.format(Value::new(0x6060_6060, 32, false), []);
+        assert!(report.contains("| chars | ````` ```` ````` |"));
+        let report = ReportFormats::Markdown.format(Value::new(0x4160_4141, 32, false), []);
+        assert!(report.contains("| chars | ``A`AA`` |"));
+    }
+
+    #[test]
+    fn json_nests_the_fields() {
+        let report =
+            ReportFormats::Json.format(Value::new(0x12, 32, false), [("hex", 0xf0), ("low", 0x0f)]);
+        let json: serde_json::Value = serde_json::from_str(&report).unwrap();
+        assert_eq!(json["hex"], "0x00000012");
+        assert_eq!(json["fields"]["hex"], "1 (0x1)");
+        assert_eq!(json["fields"]["low"], "2 (0x2)");
+    }
+
+    #[test]
+    fn rows_follow_width_and_sign() {
+        let report = ReportFormats::Json.format(Value::new(0xff, 8, true), []);
+        let json: serde_json::Value = serde_json::from_str(&report).unwrap();
+        assert_eq!(json["hex"], "0xff");
+        assert_eq!(json["decimal"], "-1");
+        assert_eq!(json["unsigned"], "255");
+        assert_eq!(json["signed"], "-1");
+        assert_eq!(json["octal"], "0o377");
+        assert_eq!(json["binary"], "0b11111111");
+        assert_eq!(json["chars"], ".");
+        assert!(json.get("float").is_none());
+    }
+}
@@ -1,52 +1,95 @@
+use crate::color::Swatch;
+use crate::config::Config;
+use crate::custom_theme::{CustomTheme, PaletteEntry};
 use crate::language_type::LanguageType;
+use crate::number_locale::NumberLocale;
 use crate::theme_type::ThemeType;
-use fluent_templates::loader::langid;
-use fluent_templates::{static_loader, LanguageIdentifier, Loader};
-use iced::widget::{column, horizontal_rule, radio, text};
-use iced::{theme, Color, Element, Theme};
-
-const GERMAN: LanguageIdentifier = langid!("de");
-const ENGLISH: LanguageIdentifier = langid!("en");
-
-static_loader! {
-    static LOCALES = {
-        locales: "locales",
-        fallback_language: "en",
-    };
-}
+use crate::translations::{set_language, tr, tr_args, translations, ENGLISH};
+use crate::ui_scale::UiScale;
+use binary_calculator::format_template::FormatTemplate;
+use fluent_templates::LanguageIdentifier;
+use iced::widget::{
+    button, checkbox, column, container, horizontal_rule, pick_list, radio, row, scrollable, text,
+    text_input, Column,
+};
+use iced::{theme, Alignment, Element, Length, Theme};
+use num_format::Locale;
+use std::path::PathBuf;
 
 pub(crate) struct BinaryCalulatorSettings {
     theme: Theme,
     language: LanguageType,
+    number_locale: NumberLocale,
+    scale: UiScale,
     setting_str: String,
     copy_to_clipboard_str: String,
     main_str: String,
+    network_str: String,
+    permissions_str: String,
+    color_str: String,
+    timestamp_str: String,
+    mask_str: String,
     decimal_str: String,
     hexadecimal_str: String,
     octal_str: String,
+    binary_str: String,
     choose_a_theme_str: String,
     choose_a_language_str: String,
     light_str: String,
     dark_str: String,
     custom_str: String,
+    copy_formats_str: String,
+    number_format_str: String,
+    /// The copy formats defined by the user.
+    copy_templates: Vec<FormatTemplate>,
+    /// The copy format being edited.
+    template_draft: FormatTemplate,
+    /// The palettes for `ThemeType::Custom`, never empty.
+    custom_themes: Vec<CustomTheme>,
+    /// The index of the custom theme in use and being edited.
+    custom_theme: usize,
+    /// The name typed for the custom theme, which is renamed while the
+    /// name is not taken by another theme.
+    theme_name: String,
+    /// The file to import a theme from or export it to.
+    theme_path: String,
+    /// The result of the last import or export.
+    theme_status: String,
 }
 
 impl<'a> BinaryCalulatorSettings {
     pub fn new() -> Self {
         Self {
             theme: Theme::Dark,
-            language: LanguageType::English,
-            setting_str: LOCALES.lookup(&ENGLISH, "settings").unwrap(),
-            copy_to_clipboard_str: LOCALES.lookup(&ENGLISH, "copy_to_clipboard").unwrap(),
-            main_str: LOCALES.lookup(&ENGLISH, "main").unwrap(),
-            decimal_str: LOCALES.lookup(&ENGLISH, "decimal").unwrap(),
-            hexadecimal_str: LOCALES.lookup(&ENGLISH, "hexadecimal").unwrap(),
-            octal_str: LOCALES.lookup(&ENGLISH, "octal").unwrap(),
-            choose_a_theme_str: LOCALES.lookup(&ENGLISH, "choose_a_theme").unwrap(),
-            choose_a_language_str: LOCALES.lookup(&ENGLISH, "choose_a_language").unwrap(),
-            light_str: LOCALES.lookup(&ENGLISH, "light").unwrap(),
-            dark_str: LOCALES.lookup(&ENGLISH, "dark").unwrap(),
-            custom_str: LOCALES.lookup(&ENGLISH, "custom").unwrap(),
+            language: LanguageType::default(),
+            number_locale: NumberLocale::default(),
+            scale: UiScale::default(),
+            setting_str: translations().lookup(&ENGLISH, "settings"),
+            copy_to_clipboard_str: translations().lookup(&ENGLISH, "copy_to_clipboard"),
+            main_str: translations().lookup(&ENGLISH, "main"),
+            network_str: translations().lookup(&ENGLISH, "network"),
+            permissions_str: translations().lookup(&ENGLISH, "permissions"),
+            color_str: translations().lookup(&ENGLISH, "color"),
+            timestamp_str: translations().lookup(&ENGLISH, "timestamp"),
+            mask_str: translations().lookup(&ENGLISH, "mask"),
+            decimal_str: translations().lookup(&ENGLISH, "decimal"),
+            hexadecimal_str: translations().lookup(&ENGLISH, "hexadecimal"),
+            octal_str: translations().lookup(&ENGLISH, "octal"),
+            binary_str: translations().lookup(&ENGLISH, "binary"),
+            choose_a_theme_str: translations().lookup(&ENGLISH, "choose_a_theme"),
+            choose_a_language_str: translations().lookup(&ENGLISH, "choose_a_language"),
+            light_str: translations().lookup(&ENGLISH, "light"),
+            dark_str: translations().lookup(&ENGLISH, "dark"),
+            custom_str: translations().lookup(&ENGLISH, "custom"),
+            copy_formats_str: translations().lookup(&ENGLISH, "copy_formats"),
+            number_format_str: translations().lookup(&ENGLISH, "number_format"),
+            copy_templates: Vec::new(),
+            template_draft: FormatTemplate::default(),
+            custom_themes: vec![CustomTheme::default()],
+            custom_theme: 0,
+            theme_name: CustomTheme::default().name,
+            theme_path: String::new(),
+            theme_status: String::new(),
         }
     }
 
@@ -56,20 +99,94 @@ impl<'a> BinaryCalulatorSettings {
                 self.theme = match theme {
                     ThemeType::Light => Theme::Light,
                     ThemeType::Dark => Theme::Dark,
-                    ThemeType::Custom => Theme::custom(theme::Palette {
-                        background: Color::from_rgb(1.0, 0.9, 1.0),
-                        text: Color::BLACK,
-                        primary: Color::from_rgb(0.5, 0.5, 0.0),
-                        success: Color::from_rgb(0.0, 1.0, 0.0),
-                        danger: Color::from_rgb(1.0, 0.0, 0.0),
-                    }),
+                    ThemeType::Custom => {
+                        Theme::custom(self.custom_themes[self.custom_theme].palette())
+                    }
+                }
+            }
+            SettingsMessage::CustomThemeSelected(name) => {
+                if let Some(index) = self.custom_themes.iter().position(|t| t.name == name) {
+                    self.custom_theme = index;
+                    self.refresh_custom_theme();
+                }
+            }
+            SettingsMessage::CustomThemeChanged(custom_theme) => {
+                self.custom_themes[self.custom_theme] = custom_theme;
+                self.refresh_custom_theme();
+            }
+            SettingsMessage::CustomThemeRenamed(name) => {
+                // The themes are selected by name, so names must be unique.
+                let taken = self
+                    .custom_themes
+                    .iter()
+                    .enumerate()
+                    .any(|(index, t)| index != self.custom_theme && t.name == name);
+                self.theme_status = if taken {
+                    tr_args("theme_name_taken", &[("name", name.as_str().into())])
+                } else {
+                    self.custom_themes[self.custom_theme].name = name.clone();
+                    String::new()
+                };
+                self.theme_name = name;
+            }
+            SettingsMessage::CustomThemeAdded => {
+                let name = (self.custom_themes.len() + 1..)
+                    .map(|number| tr_args("custom_theme_name", &[("number", number.into())]))
+                    .find(|name| self.custom_themes.iter().all(|t| &t.name != name))
+                    .unwrap_or_default();
+                let custom_theme = CustomTheme {
+                    name,
+                    ..self.custom_themes[self.custom_theme].clone()
+                };
+                self.add_custom_theme(custom_theme);
+            }
+            SettingsMessage::CustomThemeRemoved => {
+                if self.custom_themes.len() > 1 {
+                    self.custom_themes.remove(self.custom_theme);
+                    self.custom_theme = self.custom_theme.min(self.custom_themes.len() - 1);
+                    self.refresh_custom_theme();
+                }
+            }
+            SettingsMessage::ThemePathChanged(path) => self.theme_path = path,
+            SettingsMessage::ThemeImported => {
+                self.theme_status = match CustomTheme::import(&self.theme_file()) {
+                    Ok(mut custom_theme) => {
+                        custom_theme.name = self.unused_theme_name(&custom_theme.name);
+                        let status =
+                            tr_args("imported", &[("name", custom_theme.name.as_str().into())]);
+                        self.add_custom_theme(custom_theme);
+                        status
+                    }
+                    Err(error) => error.to_string(),
+                }
+            }
+            SettingsMessage::ThemeExported => {
+                let path = self.theme_file();
+                self.theme_status = match self.custom_themes[self.custom_theme].export(&path) {
+                    Ok(()) => tr_args("exported", &[("path", path.display().to_string().into())]),
+                    Err(error) => error.to_string(),
                 }
             }
             SettingsMessage::Language(lang) => {
+                self.read_language(&lang.0);
                 self.language = lang;
-                match lang {
-                    LanguageType::English => self.read_language(&ENGLISH),
-                    LanguageType::Deutsch => self.read_language(&GERMAN),
+            }
+            SettingsMessage::NumberLocale(locale) => self.number_locale = locale,
+            SettingsMessage::Scale(scale) => self.scale = scale,
+            SettingsMessage::TemplateChanged(template) => self.template_draft = template,
+            SettingsMessage::TemplateAdded => {
+                let name = self.template_draft.name.trim().to_string();
+                if !name.is_empty() {
+                    self.copy_templates.retain(|template| template.name != name);
+                    self.copy_templates.push(FormatTemplate {
+                        name,
+                        ..self.template_draft.clone()
+                    });
+                }
+            }
+            SettingsMessage::TemplateRemoved(index) => {
+                if index < self.copy_templates.len() {
+                    self.copy_templates.remove(index);
                 }
             }
         };
@@ -86,48 +203,400 @@ impl<'a> BinaryCalulatorSettings {
                         ThemeType::Custom => &self.custom_str,
                     },
                     *theme,
-                    Some(match self.theme {
-                        Theme::Light => ThemeType::Light,
-                        Theme::Dark => ThemeType::Dark,
-                        Theme::Custom { .. } => ThemeType::Custom,
-                    }),
+                    Some(self.theme_type()),
                     SettingsMessage::ThemeChanged,
                 ))
             },
         );
-        let choose_language = LanguageType::ALL.iter().fold(
-            column![text(&self.choose_a_language_str)].spacing(10),
-            |column, lang| {
-                column.push(radio(
-                    format!("{:?}", lang),
-                    *lang,
-                    Some(self.language),
-                    SettingsMessage::Language,
-                ))
+        let choose_language = column![
+            text(&self.choose_a_language_str),
+            pick_list(
+                LanguageType::all(),
+                Some(self.language.clone()),
+                SettingsMessage::Language
+            )
+            .width(Length::Units(150)),
+        ]
+        .spacing(10);
+        let choose_number_locale = column![
+            text(&self.number_format_str),
+            pick_list(
+                NumberLocale::all(),
+                Some(self.number_locale),
+                SettingsMessage::NumberLocale
+            )
+            .width(Length::Units(150)),
+        ]
+        .spacing(10);
+        let choose_scale = column![
+            text(tr("ui_scale")),
+            pick_list(&UiScale::ALL[..], Some(self.scale), SettingsMessage::Scale)
+                .width(Length::Units(100)),
+        ]
+        .spacing(10);
+        let content = column![
+            horizontal_rule(38),
+            row![
+                choose_theme,
+                choose_language,
+                choose_number_locale,
+                choose_scale
+            ]
+            .spacing(40),
+            self.theme_editor(),
+            horizontal_rule(38),
+            self.template_editor(),
+        ]
+        .spacing(20);
+        scrollable(content).into()
+    }
+
+    fn theme_editor(&self) -> Element<'a, SettingsMessage> {
+        let selected = &self.custom_themes[self.custom_theme];
+        let names: Vec<String> = self
+            .custom_themes
+            .iter()
+            .map(|custom_theme| custom_theme.name.clone())
+            .collect();
+        let name_input = text_input(
+            &tr("name"),
+            &self.theme_name,
+            SettingsMessage::CustomThemeRenamed,
+        )
+        .width(Length::Units(150));
+        let entries = PaletteEntry::ALL
+            .iter()
+            .fold(Column::new().spacing(5), |column, entry| {
+                let entry = *entry;
+                let entry_theme = selected.clone();
+                column.push(
+                    row![
+                        text(entry).width(Length::Units(100)),
+                        text_input("#RRGGBB", selected.color(entry), move |color| {
+                            let mut custom_theme = entry_theme.clone();
+                            custom_theme.set_color(entry, color);
+                            SettingsMessage::CustomThemeChanged(custom_theme)
+                        })
+                        .width(Length::Units(100)),
+                        container(text(""))
+                            .width(Length::Units(30))
+                            .height(Length::Units(20))
+                            .style(theme::Container::Custom(Box::new(Swatch(
+                                selected.parsed_color(entry)
+                            )))),
+                    ]
+                    .spacing(10)
+                    .align_items(Alignment::Center),
+                )
+            });
+        let palette = selected.palette();
+        let preview = container(
+            column![
+                text(format!("{}: 0x0011eeff", selected.name))
+                    .style(theme::Text::Color(palette.text)),
+                row![
+                    text(PaletteEntry::Primary).style(theme::Text::Color(palette.primary)),
+                    text(PaletteEntry::Success).style(theme::Text::Color(palette.success)),
+                    text(PaletteEntry::Danger).style(theme::Text::Color(palette.danger)),
+                ]
+                .spacing(10),
+            ]
+            .spacing(10),
+        )
+        .padding(10)
+        .width(Length::Units(250))
+        .style(theme::Container::Custom(Box::new(Swatch(
+            palette.background,
+        ))));
+        column![
+            text(&self.custom_str),
+            row![
+                pick_list(
+                    names,
+                    Some(selected.name.clone()),
+                    SettingsMessage::CustomThemeSelected
+                )
+                .width(Length::Units(150)),
+                name_input,
+                button("+").on_press(SettingsMessage::CustomThemeAdded),
+                button("x").on_press(SettingsMessage::CustomThemeRemoved),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center),
+            row![entries, preview].spacing(20),
+            row![
+                text_input(
+                    &self.theme_file().display().to_string(),
+                    &self.theme_path,
+                    SettingsMessage::ThemePathChanged
+                )
+                .width(Length::Units(300)),
+                button(text(tr("import"))).on_press(SettingsMessage::ThemeImported),
+                button(text(tr("export"))).on_press(SettingsMessage::ThemeExported),
+                text(&self.theme_status),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center),
+        ]
+        .spacing(10)
+        .into()
+    }
+
+    /// Applies the edited custom theme if it is in use, as a live preview,
+    /// and shows its name.
+    fn refresh_custom_theme(&mut self) {
+        self.theme_name = self.custom_themes[self.custom_theme].name.clone();
+        if self.theme_type() == ThemeType::Custom {
+            self.update(SettingsMessage::ThemeChanged(ThemeType::Custom));
+        }
+    }
+
+    /// Adds the theme, whose name must be unused, and selects it.
+    fn add_custom_theme(&mut self, custom_theme: CustomTheme) {
+        self.custom_themes.push(custom_theme);
+        self.custom_theme = self.custom_themes.len() - 1;
+        self.refresh_custom_theme();
+    }
+
+    /// The name, or the name with the lowest free number appended, e.g.
+    /// `Pink 2`, if a theme has it already.
+    fn unused_theme_name(&self, name: &str) -> String {
+        std::iter::once(name.to_string())
+            .chain((2..).map(|number| format!("{name} {number}")))
+            .find(|name| self.custom_themes.iter().all(|t| &t.name != name))
+            .unwrap_or_default()
+    }
+
+    /// The theme file entered, or `<name>.toml` next to the settings file.
+    fn theme_file(&self) -> PathBuf {
+        if !self.theme_path.trim().is_empty() {
+            return PathBuf::from(self.theme_path.trim());
+        }
+        // A theme name may contain `/`, which must not lead into another directory.
+        let name: String = self.custom_themes[self.custom_theme]
+            .name
+            .chars()
+            .filter(|c| !std::path::is_separator(*c))
+            .collect();
+        let file = format!("{name}.toml");
+        Config::path()
+            .and_then(|path| path.parent().map(|dir| dir.join(&file)))
+            .unwrap_or_else(|| PathBuf::from(file))
+    }
+
+    fn template_editor(&self) -> Element<'a, SettingsMessage> {
+        let draft = &self.template_draft;
+        let edit = |change: fn(&mut FormatTemplate, String)| {
+            let draft = draft.clone();
+            move |input: String| {
+                let mut template = draft.clone();
+                change(&mut template, input);
+                SettingsMessage::TemplateChanged(template)
+            }
+        };
+        let toggle = |change: fn(&mut FormatTemplate, bool)| {
+            let draft = draft.clone();
+            move |checked: bool| {
+                let mut template = draft.clone();
+                change(&mut template, checked);
+                SettingsMessage::TemplateChanged(template)
+            }
+        };
+        let radix_draft = draft.clone();
+        let radix_pick_list = pick_list(
+            &FormatTemplate::RADIXES[..],
+            Some(draft.radix),
+            move |radix| {
+                SettingsMessage::TemplateChanged(FormatTemplate {
+                    radix,
+                    ..radix_draft.clone()
+                })
+            },
+        )
+        .width(Length::Units(60));
+        let group_bits_draft = draft.clone();
+        let group_bits_pick_list = pick_list(
+            &FormatTemplate::GROUP_BITS[..],
+            Some(draft.group_bits),
+            move |group_bits| {
+                SettingsMessage::TemplateChanged(FormatTemplate {
+                    group_bits,
+                    ..group_bits_draft.clone()
+                })
+            },
+        )
+        .width(Length::Units(60));
+        let templates = self.copy_templates.iter().enumerate().fold(
+            Column::new().spacing(5),
+            |column, (index, template)| {
+                column.push(
+                    row![
+                        button("x").on_press(SettingsMessage::TemplateRemoved(index)),
+                        text(template)
+                    ]
+                    .spacing(10)
+                    .align_items(Alignment::Center),
+                )
             },
         );
-        let content = column![horizontal_rule(38), choose_theme, choose_language].spacing(20);
-        content.into()
+        column![
+            text(&self.copy_formats_str),
+            row![
+                text_input(&tr("name"), &draft.name, edit(|t, s| t.name = s))
+                    .width(Length::Units(150)),
+                radix_pick_list,
+                group_bits_pick_list,
+                text_input(
+                    &tr("separator"),
+                    &draft.separator,
+                    edit(|t, s| t.separator = s)
+                )
+                .width(Length::Units(80)),
+                text_input(&tr("prefix"), &draft.prefix, edit(|t, s| t.prefix = s))
+                    .width(Length::Units(80)),
+                text_input(&tr("suffix"), &draft.suffix, edit(|t, s| t.suffix = s))
+                    .width(Length::Units(80)),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center),
+            row![
+                text_input(
+                    "(",
+                    &draft.group_brackets.0,
+                    edit(|t, s| t.group_brackets.0 = s)
+                )
+                .width(Length::Units(40)),
+                text_input(
+                    ")",
+                    &draft.group_brackets.1,
+                    edit(|t, s| t.group_brackets.1 = s)
+                )
+                .width(Length::Units(40)),
+                text_input("[", &draft.brackets.0, edit(|t, s| t.brackets.0 = s))
+                    .width(Length::Units(40)),
+                text_input("]", &draft.brackets.1, edit(|t, s| t.brackets.1 = s))
+                    .width(Length::Units(40)),
+                checkbox("ABC", draft.uppercase, toggle(|t, b| t.uppercase = b)),
+                checkbox(
+                    "Intel",
+                    draft.little_endian,
+                    toggle(|t, b| t.little_endian = b)
+                ),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center),
+            row![
+                text(draft.format(0x0011eeff)).width(Length::Units(300)),
+                button("+").on_press(SettingsMessage::TemplateAdded),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center),
+            templates,
+        ]
+        .spacing(10)
+        .into()
     }
 
     fn read_language(&mut self, lang: &LanguageIdentifier) {
-        self.setting_str = LOCALES.lookup(lang, "settings").unwrap();
-        self.copy_to_clipboard_str = LOCALES.lookup(lang, "copy_to_clipboard").unwrap();
-        self.main_str = LOCALES.lookup(lang, "main").unwrap();
-        self.decimal_str = LOCALES.lookup(lang, "decimal").unwrap();
-        self.hexadecimal_str = LOCALES.lookup(lang, "hexadecimal").unwrap();
-        self.octal_str = LOCALES.lookup(lang, "octal").unwrap();
-        self.choose_a_theme_str = LOCALES.lookup(lang, "choose_a_theme").unwrap();
-        self.choose_a_language_str = LOCALES.lookup(lang, "choose_a_language").unwrap();
-        self.light_str = LOCALES.lookup(lang, "light").unwrap();
-        self.dark_str = LOCALES.lookup(lang, "dark").unwrap();
-        self.custom_str = LOCALES.lookup(lang, "custom").unwrap();
+        set_language(lang);
+        self.setting_str = translations().lookup(lang, "settings");
+        self.copy_to_clipboard_str = translations().lookup(lang, "copy_to_clipboard");
+        self.main_str = translations().lookup(lang, "main");
+        self.network_str = translations().lookup(lang, "network");
+        self.permissions_str = translations().lookup(lang, "permissions");
+        self.color_str = translations().lookup(lang, "color");
+        self.timestamp_str = translations().lookup(lang, "timestamp");
+        self.mask_str = translations().lookup(lang, "mask");
+        self.decimal_str = translations().lookup(lang, "decimal");
+        self.hexadecimal_str = translations().lookup(lang, "hexadecimal");
+        self.octal_str = translations().lookup(lang, "octal");
+        self.binary_str = translations().lookup(lang, "binary");
+        self.choose_a_theme_str = translations().lookup(lang, "choose_a_theme");
+        self.choose_a_language_str = translations().lookup(lang, "choose_a_language");
+        self.light_str = translations().lookup(lang, "light");
+        self.dark_str = translations().lookup(lang, "dark");
+        self.custom_str = translations().lookup(lang, "custom");
+        self.copy_formats_str = translations().lookup(lang, "copy_formats");
+        self.number_format_str = translations().lookup(lang, "number_format");
+    }
+
+    /// Takes over the saved settings.
+    pub fn apply_config(&mut self, config: &Config) {
+        if !config.custom_themes.is_empty() {
+            // Keep the first of themes with the same name, e.g. in an edited file.
+            self.custom_themes.clear();
+            for custom_theme in &config.custom_themes {
+                if self
+                    .custom_themes
+                    .iter()
+                    .all(|t| t.name != custom_theme.name)
+                {
+                    self.custom_themes.push(custom_theme.clone());
+                }
+            }
+        }
+        self.custom_theme = self
+            .custom_themes
+            .iter()
+            .position(|t| Some(&t.name) == config.custom_theme.as_ref())
+            .unwrap_or(0);
+        self.theme_name = self.custom_themes[self.custom_theme].name.clone();
+        self.update(SettingsMessage::ThemeChanged(config.theme));
+        self.update(SettingsMessage::Language(
+            config.language.clone().unwrap_or_else(LanguageType::system),
+        ));
+        self.number_locale = config
+            .number_locale
+            .as_deref()
+            .and_then(|name| Locale::from_name(name).ok())
+            .map_or(NumberLocale::Language, NumberLocale::Named);
+        self.copy_templates = config.copy_templates.clone();
+        self.scale = UiScale(config.scale.clamp(50, 300));
+    }
+
+    /// Writes the settings to be saved into `config`.
+    pub fn store_config(&self, config: &mut Config) {
+        config.theme = self.theme_type();
+        config.language = Some(self.language.clone());
+        config.number_locale = match self.number_locale {
+            NumberLocale::Language => None,
+            NumberLocale::Named(locale) => Some(locale.name().to_string()),
+        };
+        config.copy_templates = self.copy_templates.clone();
+        config.scale = self.scale.0;
+        config.custom_themes = self.custom_themes.clone();
+        config.custom_theme = Some(self.custom_themes[self.custom_theme].name.clone());
+    }
+
+    fn theme_type(&self) -> ThemeType {
+        match self.theme {
+            Theme::Light => ThemeType::Light,
+            Theme::Dark => ThemeType::Dark,
+            Theme::Custom { .. } => ThemeType::Custom,
+        }
     }
 
     pub(crate) fn theme(&self) -> &Theme {
         &self.theme
     }
 
+    /// The locale of decimal numbers, the selected one or the one of the language.
+    pub(crate) fn number_locale(&self) -> Locale {
+        match self.number_locale {
+            NumberLocale::Named(locale) => locale,
+            NumberLocale::Language => self.language.number_locale(),
+        }
+    }
+
+    /// The zoom of the user interface.
+    pub(crate) fn scale(&self) -> UiScale {
+        self.scale
+    }
+
+    pub(crate) fn copy_templates(&self) -> &[FormatTemplate] {
+        self.copy_templates.as_ref()
+    }
+
     pub(crate) fn setting_str(&self) -> &str {
         self.setting_str.as_ref()
     }
@@ -140,6 +609,26 @@ impl<'a> BinaryCalulatorSettings {
         self.main_str.as_ref()
     }
 
+    pub(crate) fn network_str(&self) -> &str {
+        self.network_str.as_ref()
+    }
+
+    pub(crate) fn permissions_str(&self) -> &str {
+        self.permissions_str.as_ref()
+    }
+
+    pub(crate) fn color_str(&self) -> &str {
+        self.color_str.as_ref()
+    }
+
+    pub(crate) fn timestamp_str(&self) -> &str {
+        self.timestamp_str.as_ref()
+    }
+
+    pub(crate) fn mask_str(&self) -> &str {
+        self.mask_str.as_ref()
+    }
+
     pub(crate) fn decimal_str(&self) -> &str {
         self.decimal_str.as_ref()
     }
@@ -151,10 +640,27 @@ impl<'a> BinaryCalulatorSettings {
     pub(crate) fn octal_str(&self) -> &str {
         self.octal_str.as_ref()
     }
+
+    pub(crate) fn binary_str(&self) -> &str {
+        self.binary_str.as_ref()
+    }
 }
 
 #[derive(Debug, Clone)]
 pub(crate) enum SettingsMessage {
     ThemeChanged(ThemeType),
     Language(LanguageType),
+    NumberLocale(NumberLocale),
+    Scale(UiScale),
+    CustomThemeSelected(String),
+    CustomThemeChanged(CustomTheme),
+    CustomThemeRenamed(String),
+    CustomThemeAdded,
+    CustomThemeRemoved,
+    ThemePathChanged(String),
+    ThemeImported,
+    ThemeExported,
+    TemplateChanged(FormatTemplate),
+    TemplateAdded,
+    TemplateRemoved(usize),
 }
@@ -0,0 +1,120 @@
+use std::net::Ipv4Addr;
+
+/// An IPv4 address together with the prefix length of its subnet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Subnet {
+    address: u32,
+    prefix: u8,
+}
+
+impl Subnet {
+    pub fn new(address: u32, prefix: u8) -> Self {
+        Self {
+            address,
+            prefix: prefix.min(32),
+        }
+    }
+
+    pub fn mask(&self) -> u32 {
+        prefix_to_mask(self.prefix)
+    }
+
+    pub fn wildcard(&self) -> u32 {
+        !self.mask()
+    }
+
+    pub fn network(&self) -> u32 {
+        self.address & self.mask()
+    }
+
+    pub fn broadcast(&self) -> u32 {
+        self.address | self.wildcard()
+    }
+
+    /// The first usable host address. Point-to-point links (/31) and single
+    /// hosts (/32) have no network and broadcast address to skip.
+    pub fn first_host(&self) -> u32 {
+        if self.prefix >= 31 {
+            self.network()
+        } else {
+            self.network() + 1
+        }
+    }
+
+    pub fn last_host(&self) -> u32 {
+        if self.prefix >= 31 {
+            self.broadcast()
+        } else {
+            self.broadcast() - 1
+        }
+    }
+
+    pub fn hosts(&self) -> u64 {
+        let size = 1_u64 << (32 - self.prefix);
+        if self.prefix >= 31 {
+            size
+        } else {
+            size - 2
+        }
+    }
+}
+
+/// Converts a prefix length into the subnet mask, e.g. 24 into 255.255.255.0.
+pub fn prefix_to_mask(prefix: u8) -> u32 {
+    match prefix {
+        0 => 0,
+        1..=31 => u32::MAX << (32 - prefix),
+        _ => u32::MAX,
+    }
+}
+
+/// Parses an address in dotted quad notation with an optional prefix length
+/// in CIDR notation, e.g. `10.0.0.1` or `10.0.0.1/8`.
+pub fn parse_cidr(input: &str) -> Option<(u32, Option<u8>)> {
+    let input = input.trim();
+    let (address, prefix) = match input.split_once('/') {
+        Some((address, prefix)) => {
+            let prefix = prefix.trim().parse::<u8>().ok().filter(|p| *p <= 32)?;
+            (address, Some(prefix))
+        }
+        None => (input, None),
+    };
+    let address = address.trim().parse::<Ipv4Addr>().ok()?;
+    Some((u32::from(address), prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cidr() {
+        assert_eq!(parse_cidr("10.0.0.1"), Some((0x0a00_0001, None)));
+        assert_eq!(
+            parse_cidr(" 192.168.1.7 / 24 "),
+            Some((0xc0a8_0107, Some(24)))
+        );
+        assert_eq!(parse_cidr("10.0.0.1/33"), None);
+        assert_eq!(parse_cidr("10.0.0/8"), None);
+    }
+
+    #[test]
+    fn subnets() {
+        let subnet = Subnet::new(0xc0a8_0107, 24);
+        assert_eq!(subnet.mask(), 0xffff_ff00);
+        assert_eq!(subnet.wildcard(), 0x0000_00ff);
+        assert_eq!(subnet.network(), 0xc0a8_0100);
+        assert_eq!(subnet.broadcast(), 0xc0a8_01ff);
+        assert_eq!(subnet.first_host(), 0xc0a8_0101);
+        assert_eq!(subnet.last_host(), 0xc0a8_01fe);
+        assert_eq!(subnet.hosts(), 254);
+        let link = Subnet::new(0x0a00_0001, 31);
+        assert_eq!(
+            (link.first_host(), link.last_host()),
+            (0x0a00_0000, 0x0a00_0001)
+        );
+        assert_eq!(link.hosts(), 2);
+        assert_eq!(Subnet::new(0, 0).hosts(), (1 << 32) - 2);
+        assert_eq!(prefix_to_mask(40), u32::MAX);
+    }
+}
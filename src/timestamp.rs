@@ -0,0 +1,135 @@
+use chrono::{Local, TimeZone};
+use iced::widget::{column, pick_list, row, text, text_input, Column};
+use iced::{Alignment, Element, Length};
+
+use crate::translations::{tr, tr_args, Translated};
+use binary_calculator::timestamp_formats::{
+    gps_week, ntp_short_from_seconds, ntp_short_seconds, parse_iso_8601, TimestampFormats,
+};
+
+pub(crate) struct TimestampPanel {
+    /// The format the typed date is encoded into.
+    format: TimestampFormats,
+    /// The ISO-8601 date being typed.
+    input: String,
+    /// Whether the last submitted date could not be encoded.
+    invalid: bool,
+}
+
+impl<'a> TimestampPanel {
+    pub fn new() -> Self {
+        Self {
+            format: TimestampFormats::default(),
+            input: String::new(),
+            invalid: false,
+        }
+    }
+
+    pub fn update(&mut self, msg: TimestampMessage, value: &mut u32) {
+        match msg {
+            TimestampMessage::FormatChanged(format) => {
+                self.format = format;
+                self.invalid = false;
+            }
+            TimestampMessage::InputChanged(input) => {
+                self.input = input;
+                self.invalid = false;
+            }
+            TimestampMessage::InputSubmitted => match self.encode() {
+                Some(new_value) => {
+                    *value = new_value;
+                    self.input.clear();
+                }
+                None => self.invalid = true,
+            },
+        }
+    }
+
+    pub fn view(&self, value: u32) -> Element<'a, TimestampMessage> {
+        let header = row![
+            text("").width(Length::Units(160)),
+            text(tr("utc")).width(Length::Units(260)),
+            text(tr("local")),
+        ];
+        let table = TimestampFormats::ALL.iter().fold(
+            Column::new().spacing(5).push(header),
+            |column, format| {
+                let (utc, local) = match format.decode(value) {
+                    Some(date_time) if format.is_utc() => (
+                        date_time.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+                        Local
+                            .from_utc_datetime(&date_time)
+                            .format("%Y-%m-%dT%H:%M:%S%.3f%:z")
+                            .to_string(),
+                    ),
+                    Some(date_time) => (
+                        String::new(),
+                        date_time.format("%Y-%m-%dT%H:%M:%S").to_string(),
+                    ),
+                    None if *format == TimestampFormats::NtpShort => {
+                        (format!("{:.6} s", ntp_short_seconds(value)), String::new())
+                    }
+                    None => ("-".to_string(), "-".to_string()),
+                };
+                column.push(row![
+                    text(Translated(*format)).width(Length::Units(160)),
+                    text(utc).width(Length::Units(260)),
+                    text(local),
+                ])
+            },
+        );
+        let (week, seconds) = gps_week(value);
+        let format_pick_list = pick_list(
+            TimestampFormats::ALL.map(Translated).to_vec(),
+            Some(Translated(self.format)),
+            |format| TimestampMessage::FormatChanged(format.0),
+        )
+        .width(Length::Units(200));
+        let date_input = text_input(
+            if self.format == TimestampFormats::NtpShort {
+                "1.5"
+            } else {
+                "2000-01-01T00:00:00Z"
+            },
+            &self.input,
+            TimestampMessage::InputChanged,
+        )
+        .on_submit(TimestampMessage::InputSubmitted)
+        .width(Length::Units(250));
+        let mut encode_row = row![format_pick_list, date_input]
+            .spacing(10)
+            .align_items(Alignment::Center);
+        if self.invalid {
+            encode_row = encode_row.push(text(tr("invalid_date")));
+        }
+        column![
+            table,
+            text(tr_args(
+                "gps_week",
+                &[("week", week.into()), ("seconds", seconds.into())]
+            )),
+            encode_row
+        ]
+        .spacing(20)
+        .into()
+    }
+
+    fn encode(&self) -> Option<u32> {
+        if self.format == TimestampFormats::NtpShort {
+            return ntp_short_from_seconds(self.input.trim().parse().ok()?);
+        }
+        let date_time = parse_iso_8601(&self.input)?;
+        if self.format.is_utc() {
+            self.format.encode(date_time.naive_utc())
+        } else {
+            self.format.encode(date_time.naive_local())
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum TimestampMessage {
+    FormatChanged(TimestampFormats),
+    InputChanged(String),
+    InputSubmitted,
+}
@@ -0,0 +1,256 @@
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc,
+};
+
+/// The offset between GPS time and UTC in seconds, valid since 2017-01-01.
+const GPS_LEAP_SECONDS: i64 = 18;
+/// The number of seconds in a GPS week.
+const SECONDS_PER_WEEK: u32 = 7 * 24 * 60 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimestampFormats {
+    #[default]
+    UnixSeconds,
+    UnixMilliseconds,
+    FatDateTime,
+    NtpSeconds,
+    NtpShort,
+    GpsSeconds,
+}
+
+impl TimestampFormats {
+    pub const ALL: [TimestampFormats; 6] = [
+        TimestampFormats::UnixSeconds,
+        TimestampFormats::UnixMilliseconds,
+        TimestampFormats::FatDateTime,
+        TimestampFormats::NtpSeconds,
+        TimestampFormats::NtpShort,
+        TimestampFormats::GpsSeconds,
+    ];
+
+    /// Whether the decoded time is UTC. FAT timestamps hold the local wall
+    /// clock time without a time zone and the NTP short format is a duration.
+    pub fn is_utc(&self) -> bool {
+        !matches!(
+            self,
+            TimestampFormats::FatDateTime | TimestampFormats::NtpShort
+        )
+    }
+
+    /// Decodes the value into a date and time. Returns `None` for the NTP
+    /// short format, which is a duration, and for invalid FAT dates.
+    pub fn decode(&self, value: u32) -> Option<NaiveDateTime> {
+        match self {
+            TimestampFormats::UnixSeconds => Some(unix_epoch() + Duration::seconds(value as i64)),
+            TimestampFormats::UnixMilliseconds => {
+                Some(unix_epoch() + Duration::milliseconds(value as i64))
+            }
+            TimestampFormats::FatDateTime => {
+                let date = value >> 16;
+                let time = value & 0xFFFF;
+                NaiveDate::from_ymd_opt(1980 + (date >> 9) as i32, (date >> 5) & 0x0F, date & 0x1F)?
+                    .and_hms_opt(time >> 11, (time >> 5) & 0x3F, (time & 0x1F) * 2)
+            }
+            TimestampFormats::NtpSeconds => Some(ntp_epoch() + Duration::seconds(value as i64)),
+            TimestampFormats::NtpShort => None,
+            TimestampFormats::GpsSeconds => Some(
+                gps_epoch() + Duration::seconds(value as i64) - Duration::seconds(GPS_LEAP_SECONDS),
+            ),
+        }
+    }
+
+    /// Encodes a date and time into the format. Returns `None` if it is out
+    /// of the range of the format.
+    pub fn encode(&self, date_time: NaiveDateTime) -> Option<u32> {
+        match self {
+            TimestampFormats::UnixSeconds => {
+                u32::try_from((date_time - unix_epoch()).num_seconds()).ok()
+            }
+            TimestampFormats::UnixMilliseconds => {
+                u32::try_from((date_time - unix_epoch()).num_milliseconds()).ok()
+            }
+            TimestampFormats::FatDateTime => {
+                let year = u32::try_from(date_time.year() - 1980)
+                    .ok()
+                    .filter(|y| *y < 128)?;
+                let date = (year << 9) | (date_time.month() << 5) | date_time.day();
+                let time =
+                    (date_time.hour() << 11) | (date_time.minute() << 5) | (date_time.second() / 2);
+                Some((date << 16) | time)
+            }
+            TimestampFormats::NtpSeconds => {
+                u32::try_from((date_time - ntp_epoch()).num_seconds()).ok()
+            }
+            TimestampFormats::NtpShort => None,
+            TimestampFormats::GpsSeconds => {
+                u32::try_from((date_time - gps_epoch()).num_seconds() + GPS_LEAP_SECONDS).ok()
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for TimestampFormats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                TimestampFormats::UnixSeconds => "Unix seconds",
+                TimestampFormats::UnixMilliseconds => "Unix milliseconds",
+                TimestampFormats::FatDateTime => "FAT date/time",
+                TimestampFormats::NtpSeconds => "NTP seconds",
+                TimestampFormats::NtpShort => "NTP short",
+                TimestampFormats::GpsSeconds => "GPS time",
+            }
+        )
+    }
+}
+
+/// Decodes the NTP short format, 16 bit seconds and 16 bit fraction, into seconds.
+pub fn ntp_short_seconds(value: u32) -> f64 {
+    value as f64 / 65536.0
+}
+
+/// Encodes seconds into the NTP short format. Returns `None` if out of range.
+pub fn ntp_short_from_seconds(seconds: f64) -> Option<u32> {
+    let value = (seconds * 65536.0).round();
+    (0.0..=u32::MAX as f64)
+        .contains(&value)
+        .then_some(value as u32)
+}
+
+/// Splits GPS seconds into the GPS week and the seconds of the week.
+pub fn gps_week(value: u32) -> (u32, u32) {
+    (value / SECONDS_PER_WEEK, value % SECONDS_PER_WEEK)
+}
+
+fn unix_epoch() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(1970, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+}
+
+fn ntp_epoch() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(1900, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+}
+
+fn gps_epoch() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(1980, 1, 6)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+}
+
+/// Parses an ISO-8601 date with optional time and offset. Dates without an
+/// offset are taken as UTC.
+pub fn parse_iso_8601(input: &str) -> Option<DateTime<FixedOffset>> {
+    let input = input.trim();
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(input) {
+        return Some(date_time);
+    }
+    let naive = [
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(input, format).ok())
+    .or_else(|| {
+        NaiveDate::parse_from_str(input, "%Y-%m-%d")
+            .ok()?
+            .and_hms_opt(0, 0, 0)
+    })?;
+    Some(Utc.from_utc_datetime(&naive).fixed_offset())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date_time(y: i32, mo: u32, d: u32, h: u32, mi: u32, s: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, mo, d)
+            .unwrap()
+            .and_hms_opt(h, mi, s)
+            .unwrap()
+    }
+
+    #[test]
+    fn unix() {
+        let y2k = date_time(2000, 1, 1, 0, 0, 0);
+        assert_eq!(TimestampFormats::UnixSeconds.encode(y2k), Some(946_684_800));
+        assert_eq!(TimestampFormats::UnixSeconds.decode(946_684_800), Some(y2k));
+        assert_eq!(
+            TimestampFormats::UnixMilliseconds.decode(1_500),
+            Some(unix_epoch() + Duration::milliseconds(1_500))
+        );
+        assert_eq!(
+            TimestampFormats::UnixSeconds.encode(date_time(1969, 12, 31, 0, 0, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn fat() {
+        let time = date_time(2024, 5, 17, 13, 45, 30);
+        assert_eq!(
+            TimestampFormats::FatDateTime.encode(time),
+            Some(0x58b1_6daf)
+        );
+        assert_eq!(
+            TimestampFormats::FatDateTime.decode(0x58b1_6daf),
+            Some(time)
+        );
+        // Month 0 is no date.
+        assert_eq!(TimestampFormats::FatDateTime.decode(0), None);
+        assert_eq!(
+            TimestampFormats::FatDateTime.encode(date_time(1979, 1, 1, 0, 0, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn ntp_and_gps() {
+        let unix = unix_epoch();
+        assert_eq!(
+            TimestampFormats::NtpSeconds.encode(unix),
+            Some(2_208_988_800)
+        );
+        assert_eq!(
+            TimestampFormats::NtpSeconds.decode(2_208_988_800),
+            Some(unix)
+        );
+        assert_eq!(TimestampFormats::GpsSeconds.encode(gps_epoch()), Some(18));
+        assert_eq!(TimestampFormats::GpsSeconds.decode(18), Some(gps_epoch()));
+        assert_eq!(ntp_short_seconds(0x1_8000), 1.5);
+        assert_eq!(ntp_short_from_seconds(1.5), Some(0x1_8000));
+        assert_eq!(ntp_short_from_seconds(-1.0), None);
+        assert_eq!(gps_week(2 * SECONDS_PER_WEEK + 5), (2, 5));
+    }
+
+    #[test]
+    fn iso_8601() {
+        let utc = |y, mo, d, h, mi, s| Utc.from_utc_datetime(&date_time(y, mo, d, h, mi, s));
+        assert_eq!(
+            parse_iso_8601("2024-02-29T12:30:00+01:00").unwrap(),
+            utc(2024, 2, 29, 11, 30, 0)
+        );
+        assert_eq!(
+            parse_iso_8601(" 2024-02-29 12:30:15 ").unwrap(),
+            utc(2024, 2, 29, 12, 30, 15)
+        );
+        assert_eq!(
+            parse_iso_8601("2024-02-29T12:30").unwrap(),
+            utc(2024, 2, 29, 12, 30, 0)
+        );
+        assert_eq!(
+            parse_iso_8601("2024-02-29").unwrap(),
+            utc(2024, 2, 29, 0, 0, 0)
+        );
+        assert_eq!(parse_iso_8601("2023-02-29"), None);
+        assert_eq!(parse_iso_8601("yesterday"), None);
+    }
+}
@@ -0,0 +1,307 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+use binary_calculator::dec_formats::DecFormats;
+use binary_calculator::paste_parser::Layout;
+use binary_calculator::report::ReportFormats;
+use binary_calculator::timestamp_formats::TimestampFormats;
+use fluent_templates::fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use fluent_templates::loader::langid;
+use fluent_templates::{static_loader, FluentBundle, LanguageIdentifier, Loader};
+
+pub(crate) const ENGLISH: LanguageIdentifier = langid!("en");
+
+static_loader! {
+    static LOCALES = {
+        locales: "locales",
+        fallback_language: "en",
+        customise: |bundle| bundle.set_use_isolating(false),
+    };
+}
+
+/// The translations of the user interface: the ones built into the binary
+/// and the `<language>/*.ftl` bundles found in the user data directory,
+/// which add languages or override built-in entries.
+pub(crate) struct Translations {
+    user: HashMap<LanguageIdentifier, FluentBundle<FluentResource>>,
+}
+
+/// The language of the user interface.
+static LANGUAGE: RwLock<LanguageIdentifier> = RwLock::new(ENGLISH);
+
+/// Sets the language of the texts returned by [`tr`] and [`tr_args`].
+pub(crate) fn set_language(lang: &LanguageIdentifier) {
+    *LANGUAGE.write().unwrap_or_else(PoisonError::into_inner) = lang.clone();
+}
+
+/// The text of `key` in the language of the user interface.
+pub(crate) fn tr(key: &str) -> String {
+    let lang = LANGUAGE.read().unwrap_or_else(PoisonError::into_inner);
+    translations().lookup(&lang, key)
+}
+
+/// The text of `key` in the language of the user interface, with the
+/// Fluent arguments filled in, e.g. `tr_args("pasted", &[("layout", "bytes".into())])`.
+pub(crate) fn tr_args(key: &str, args: &[(&str, FluentValue)]) -> String {
+    let lang = LANGUAGE.read().unwrap_or_else(PoisonError::into_inner);
+    let args: HashMap<&str, FluentValue> = args.iter().cloned().collect();
+    translations().lookup_complete(&lang, key, Some(&args))
+}
+
+/// The translations, loaded on first use.
+pub(crate) fn translations() -> &'static Translations {
+    static TRANSLATIONS: OnceLock<Translations> = OnceLock::new();
+    TRANSLATIONS.get_or_init(Translations::load)
+}
+
+impl Translations {
+    /// The directory with the user's translations,
+    /// `$XDG_DATA_HOME/binary_calculator/locales`.
+    pub fn user_dir() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join("binary_calculator").join("locales"))
+    }
+
+    fn load() -> Self {
+        let user = Self::user_dir()
+            .map(|dir| read_bundles(&dir))
+            .unwrap_or_default();
+        Self { user }
+    }
+
+    /// Looks up the text of `key` in `lang`. Missing entries fall back to
+    /// English, and to the key itself if English lacks them too.
+    pub fn lookup(&self, lang: &LanguageIdentifier, key: &str) -> String {
+        self.lookup_complete(lang, key, None)
+    }
+
+    fn lookup_complete(
+        &self,
+        lang: &LanguageIdentifier,
+        key: &str,
+        args: Option<&HashMap<&str, FluentValue>>,
+    ) -> String {
+        let user_args = args.map(|args| {
+            let mut user_args = FluentArgs::new();
+            for (name, value) in args {
+                user_args.set(*name, value.clone());
+            }
+            user_args
+        });
+        [lang, &ENGLISH]
+            .iter()
+            .find_map(|lang| {
+                self.user
+                    .get(*lang)
+                    .and_then(|bundle| format_user(bundle, key, user_args.as_ref()))
+                    .or_else(|| LOCALES.lookup_single_language(lang, key, args))
+            })
+            .unwrap_or_else(|| key.to_string())
+    }
+
+    /// Every language with a translation, sorted by its tag.
+    pub fn languages(&self) -> Vec<LanguageIdentifier> {
+        let mut languages: Vec<LanguageIdentifier> =
+            LOCALES.locales().chain(self.user.keys()).cloned().collect();
+        languages.sort_by_key(|lang| lang.to_string());
+        languages.dedup();
+        languages
+    }
+
+    /// The name of the language in itself, e.g. `Deutsch`, or its tag if
+    /// the translation has no `language_name` entry.
+    pub fn language_name(&self, lang: &LanguageIdentifier) -> String {
+        self.user
+            .get(lang)
+            .and_then(|bundle| format_user(bundle, "language_name", None))
+            .or_else(|| LOCALES.lookup_single_language::<&str>(lang, "language_name", None))
+            .unwrap_or_else(|| lang.to_string())
+    }
+
+    /// The available language closest to the locale of the operating
+    /// system, English if none matches.
+    pub fn system_language(&self) -> LanguageIdentifier {
+        let languages = self.languages();
+        sys_locale::get_locales()
+            .filter_map(|locale| locale.replace('_', "-").parse::<LanguageIdentifier>().ok())
+            .find_map(|locale| {
+                languages
+                    .iter()
+                    .find(|lang| **lang == locale)
+                    .or_else(|| {
+                        languages
+                            .iter()
+                            .find(|lang| lang.language == locale.language)
+                    })
+                    .cloned()
+            })
+            .unwrap_or(ENGLISH)
+    }
+}
+
+/// Reads the `<language>/*.ftl` bundles in `dir`. Unreadable files and
+/// syntax errors are reported, the entries that parse are kept.
+fn read_bundles(dir: &Path) -> HashMap<LanguageIdentifier, FluentBundle<FluentResource>> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return HashMap::new();
+    };
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let lang = entry
+                .file_name()
+                .to_str()?
+                .parse::<LanguageIdentifier>()
+                .ok()?;
+            let files = fs::read_dir(entry.path()).ok()?;
+            let mut bundle = FluentBundle::new_concurrent(vec![lang.clone()]);
+            bundle.set_use_isolating(false);
+            for path in files
+                .flatten()
+                .map(|file| file.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "ftl"))
+            {
+                let source = match fs::read_to_string(&path) {
+                    Ok(source) => source,
+                    Err(error) => {
+                        eprintln!("{}: {error}", path.display());
+                        continue;
+                    }
+                };
+                let resource =
+                    FluentResource::try_new(source).unwrap_or_else(|(resource, errors)| {
+                        eprintln!("{}: {errors:?}", path.display());
+                        resource
+                    });
+                if let Err(errors) = bundle.add_resource(resource) {
+                    eprintln!("{}: {errors:?}", path.display());
+                }
+            }
+            Some((lang, bundle))
+        })
+        .collect()
+}
+
+/// Formats the message `key` of a user bundle. None if the bundle lacks it
+/// or it does not format, e.g. because it uses an argument the calculator
+/// does not pass, so the built-in text is shown instead of panicking.
+fn format_user(
+    bundle: &FluentBundle<FluentResource>,
+    key: &str,
+    args: Option<&FluentArgs>,
+) -> Option<String> {
+    let pattern = bundle.get_message(key)?.value()?;
+    let mut errors = Vec::new();
+    let text = bundle.format_pattern(pattern, args, &mut errors);
+    errors.is_empty().then(|| text.into_owned())
+}
+
+/// An item of the library shown with a translated label, e.g. in a pick list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Translated<T>(pub T);
+
+/// The translation key of the label of a library item.
+pub(crate) trait TranslationKey {
+    /// The key, none to show the label of the library, e.g. a sample value.
+    fn translation_key(&self) -> Option<&'static str>;
+}
+
+impl<T: TranslationKey + std::fmt::Display> std::fmt::Display for Translated<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0.translation_key() {
+            Some(key) => write!(f, "{}", tr(key)),
+            None => self.0.fmt(f),
+        }
+    }
+}
+
+impl TranslationKey for DecFormats {
+    fn translation_key(&self) -> Option<&'static str> {
+        match self {
+            DecFormats::LocaleSeperator => Some("locale"),
+            _ => None,
+        }
+    }
+}
+
+impl TranslationKey for ReportFormats {
+    fn translation_key(&self) -> Option<&'static str> {
+        match self {
+            ReportFormats::PlainText => Some("plain_text"),
+            _ => None,
+        }
+    }
+}
+
+impl TranslationKey for TimestampFormats {
+    fn translation_key(&self) -> Option<&'static str> {
+        Some(match self {
+            TimestampFormats::UnixSeconds => "unix_seconds",
+            TimestampFormats::UnixMilliseconds => "unix_milliseconds",
+            TimestampFormats::FatDateTime => "fat_date_time",
+            TimestampFormats::NtpSeconds => "ntp_seconds",
+            TimestampFormats::NtpShort => "ntp_short",
+            TimestampFormats::GpsSeconds => "gps_time",
+        })
+    }
+}
+
+impl TranslationKey for Layout {
+    fn translation_key(&self) -> Option<&'static str> {
+        Some(match self {
+            Layout::VerilogLiteral => "layout_verilog_literal",
+            Layout::VhdlLiteral => "layout_vhdl_literal",
+            Layout::MotorolaByteString => "layout_motorola_byte_string",
+            Layout::IntelByteString => "layout_intel_byte_string",
+            Layout::MotorolaByteArray => "layout_motorola_byte_array",
+            Layout::IntelByteArray => "layout_intel_byte_array",
+            Layout::MotorolaBytes => "layout_motorola_bytes",
+            Layout::IntelBytes => "layout_intel_bytes",
+            Layout::HexadecimalBlocks => "layout_hexadecimal_blocks",
+            Layout::GroupedDecimal => "layout_grouped_decimal",
+            Layout::Ipv4Address => "layout_ipv4_address",
+            Layout::Hexadecimal => "layout_hexadecimal",
+            Layout::Binary => "layout_binary",
+            Layout::Octal => "layout_octal",
+            Layout::SignedDecimal => "layout_signed_decimal",
+            Layout::Decimal => "layout_decimal",
+            Layout::COctal => "layout_c_octal",
+            Layout::PrefixedDecimal => "layout_prefixed_decimal",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn broken_user_entries_fall_back() {
+        let dir =
+            std::env::temp_dir().join(format!("binary_calculator-ftl-{}", std::process::id()));
+        fs::create_dir_all(dir.join("en")).unwrap();
+        fs::write(
+            dir.join("en").join("main.ftl"),
+            "settings = Options\ncopied = Copied { $txt }\nmain = { missing }\nbroken = {\n",
+        )
+        .unwrap();
+        let translations = Translations {
+            user: read_bundles(&dir),
+        };
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(translations.lookup(&ENGLISH, "settings"), "Options");
+        let args = HashMap::from([("text", FluentValue::from("0xff"))]);
+        assert_eq!(
+            translations.lookup_complete(&ENGLISH, "copied", Some(&args)),
+            "Copied 0xff"
+        );
+        assert_eq!(
+            translations.lookup(&ENGLISH, "main"),
+            LOCALES
+                .lookup_single_language::<&str>(&ENGLISH, "main", None)
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,293 @@
+use iced::Application;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::Frame;
+
+use crate::config::{Config, ConfigError};
+use crate::messages::Message;
+use crate::notification::NotificationKind;
+use crate::translations::{tr, Translated};
+use crate::BinaryCalculator;
+use binary_calculator::copy_format::CopyFormat;
+use binary_calculator::dec_formats::DecFormats;
+use binary_calculator::value::{Operation, MAX_WIDTH};
+
+/// The field of the main page which has the keyboard focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Bits,
+    Hexadecimal,
+    Decimal,
+    Octal,
+}
+
+impl Field {
+    const ALL: [Field; 4] = [
+        Field::Bits,
+        Field::Hexadecimal,
+        Field::Decimal,
+        Field::Octal,
+    ];
+
+    fn radix(&self) -> u32 {
+        match self {
+            Field::Bits => 2,
+            Field::Hexadecimal => 16,
+            Field::Decimal => 10,
+            Field::Octal => 8,
+        }
+    }
+}
+
+/// The main page of the calculator in the terminal. All changes are sent as
+/// [`Message`] to the [`BinaryCalculator`] of the window, so both behave the
+/// same and share the saved settings.
+struct Tui {
+    calculator: BinaryCalculator,
+    field: Field,
+    /// The index of the bit under the cursor.
+    bit: u32,
+    /// The index of the selected copy format of the field.
+    format: usize,
+}
+
+/// Runs the terminal UI until it is left with `q`, then saves the settings
+/// and value like the window does on close.
+pub(crate) fn run(config: Result<Config, ConfigError>, messages: Vec<Message>) -> i32 {
+    let (calculator, _) = BinaryCalculator::new((config, messages));
+    let mut tui = Tui {
+        calculator,
+        field: Field::Bits,
+        bit: 0,
+        format: 0,
+    };
+    tui.select_last_format();
+
+    let mut terminal = match ratatui::try_init() {
+        Ok(terminal) => terminal,
+        Err(err) => {
+            eprintln!("cannot open the terminal: {err}");
+            return 1;
+        }
+    };
+    let result = loop {
+        if let Err(err) = terminal.draw(|frame| tui.draw(frame)) {
+            break Err(err);
+        }
+        match event::read() {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => {
+                if !tui.key(key) {
+                    break Ok(());
+                }
+            }
+            Ok(_) => {}
+            Err(err) => break Err(err),
+        }
+    };
+    ratatui::restore();
+    let _ = tui.calculator.update(Message::CloseRequested);
+    match result {
+        Ok(()) => 0,
+        Err(err) => {
+            eprintln!("{err}");
+            1
+        }
+    }
+}
+
+impl Tui {
+    /// Handles a key press, returns `false` to leave.
+    fn key(&mut self, key: KeyEvent) -> bool {
+        let value = self.calculator.value;
+        let radix = self.field.radix();
+        let message = match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return false,
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Tab | KeyCode::BackTab => {
+                let index = Field::ALL.iter().position(|field| *field == self.field);
+                let step = if key.code == KeyCode::Tab { 1 } else { 3 };
+                self.field = Field::ALL[(index.unwrap_or(0) + step) % Field::ALL.len()];
+                self.select_last_format();
+                return true;
+            }
+            KeyCode::Left if self.field == Field::Bits => {
+                self.bit = (self.bit + 1).min(MAX_WIDTH - 1);
+                return true;
+            }
+            KeyCode::Right if self.field == Field::Bits => {
+                self.bit = self.bit.saturating_sub(1);
+                return true;
+            }
+            KeyCode::Up | KeyCode::Down => {
+                let count = self.formats().len();
+                self.format = if key.code == KeyCode::Up {
+                    (self.format + count - 1) % count
+                } else {
+                    (self.format + 1) % count
+                };
+                return true;
+            }
+            KeyCode::Char(' ') if self.field == Field::Bits => {
+                Message::Apply(Operation::ToggleBit(self.bit))
+            }
+            KeyCode::Char('<') => Message::Apply(Operation::ShiftLeft(1)),
+            KeyCode::Char('>') => Message::Apply(Operation::ShiftRight(1)),
+            KeyCode::Char('~') => Message::Apply(Operation::Not),
+            KeyCode::Char('s') => Message::SignToggled(!self.calculator.signed),
+            KeyCode::Char('p') => Message::Paste,
+            // The keys of the keypad of the field.
+            KeyCode::Char(digit) if digit.is_digit(radix) => Message::InputU32Changed(
+                value
+                    .wrapping_mul(radix)
+                    .wrapping_add(digit.to_digit(radix).unwrap_or(0)),
+            ),
+            KeyCode::Backspace => Message::InputU32Changed(value / radix),
+            KeyCode::Enter => match self.formats().into_iter().nth(self.format) {
+                Some((_, message)) => message,
+                None => return true,
+            },
+            _ => return true,
+        };
+        let _ = self.calculator.update(message);
+        true
+    }
+
+    /// The copy formats of the field with the message which copies them.
+    fn formats(&self) -> Vec<(String, Message)> {
+        let templates = self.calculator.settings.copy_templates();
+        match self.field {
+            Field::Decimal => DecFormats::ALL
+                .iter()
+                .map(|format| (Translated(*format).to_string(), Message::DecCopy(*format)))
+                .collect(),
+            field => CopyFormat::all(field.radix(), templates)
+                .into_iter()
+                .map(|format| (format.to_string(), Message::Copy(format)))
+                .collect(),
+        }
+    }
+
+    /// Selects the copy format used last for the field, like the pick lists.
+    fn select_last_format(&mut self) {
+        let last = match self.field {
+            Field::Decimal => self
+                .calculator
+                .dec_format
+                .map(|format| Translated(format).to_string()),
+            field => self
+                .calculator
+                .copy_format(field.radix())
+                .map(|format| format.to_string()),
+        };
+        self.format = self
+            .formats()
+            .iter()
+            .position(|(label, _)| Some(label) == last.as_ref())
+            .unwrap_or(0);
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let calculator = &self.calculator;
+        let settings = &calculator.settings;
+        let value = calculator.value;
+        let [main, status, help] = Layout::vertical([
+            Constraint::Fill(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let mut indices = vec![Span::raw("  ")];
+        let mut bits = vec![self.marker(Field::Bits)];
+        for bit in (0..MAX_WIDTH).rev() {
+            if bit % 4 == 3 {
+                if bit != MAX_WIDTH - 1 {
+                    indices.push(Span::raw(" "));
+                    bits.push(Span::raw(" "));
+                }
+                indices.push(Span::raw(format!("{bit:<4}")));
+            }
+            let mut span = Span::raw(if value >> bit & 1 == 1 { "1" } else { "0" });
+            if self.field == Field::Bits && bit == self.bit {
+                span = span.add_modifier(Modifier::REVERSED);
+            }
+            bits.push(span);
+        }
+        bits.push(Span::raw(format!("  {}", settings.binary_str())));
+
+        let decimal = DecFormats::LocaleSeperator
+            .format(calculator.decimal_value(), &settings.number_locale());
+        let sign = tr(if calculator.signed {
+            "signed"
+        } else {
+            "unsigned"
+        });
+        let fields = [
+            (
+                Field::Hexadecimal,
+                settings.hexadecimal_str(),
+                format!("{:04x} {:04x}", value >> 16, value & 0xFFFF),
+            ),
+            (
+                Field::Decimal,
+                settings.decimal_str(),
+                format!("{decimal} ({sign})"),
+            ),
+            (
+                Field::Octal,
+                settings.octal_str(),
+                format!(
+                    "{:02o} {:03o} {:03o} {:03o}",
+                    (value >> 27) & 0o777,
+                    (value >> 18) & 0o777,
+                    (value >> 9) & 0o777,
+                    value & 0o777
+                ),
+            ),
+        ];
+
+        let mut lines = vec![Line::from(indices), Line::from(bits), Line::default()];
+        for (field, label, text) in fields {
+            lines.push(Line::from(vec![
+                self.marker(field),
+                Span::raw(format!("{label:<16}")),
+                Span::raw(text).bold(),
+            ]));
+        }
+        let format = self
+            .formats()
+            .into_iter()
+            .nth(self.format)
+            .map(|(label, _)| label)
+            .unwrap_or_default();
+        lines.push(Line::default());
+        lines.push(Line::from(format!(
+            "  {}: < {format} >",
+            settings.copy_to_clipboard_str()
+        )));
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title(tr("title"))),
+            main,
+        );
+
+        if let Some(notification) = &calculator.notification {
+            let color = match notification.kind {
+                NotificationKind::Success => Color::Green,
+                NotificationKind::Error => Color::Red,
+            };
+            frame.render_widget(
+                Paragraph::new(notification.text.as_str()).style(Style::new().fg(color)),
+                status,
+            );
+        }
+        frame.render_widget(Paragraph::new(tr("tui_help")).dim(), help);
+    }
+
+    /// The mark in front of the focused field.
+    fn marker(&self, field: Field) -> Span<'static> {
+        Span::raw(if self.field == field { "> " } else { "  " })
+    }
+}
@@ -0,0 +1,33 @@
+/// The zoom of the user interface in percent, on top of the scale of the
+/// display, for larger text and controls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct UiScale(pub u16);
+
+impl UiScale {
+    pub const ALL: [Self; 7] = [
+        Self(75),
+        Self(90),
+        Self(100),
+        Self(125),
+        Self(150),
+        Self(175),
+        Self(200),
+    ];
+
+    /// The scale factor of the application.
+    pub fn factor(&self) -> f64 {
+        f64::from(self.0) / 100.0
+    }
+}
+
+impl Default for UiScale {
+    fn default() -> Self {
+        Self(100)
+    }
+}
+
+impl std::fmt::Display for UiScale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} %", self.0)
+    }
+}
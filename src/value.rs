@@ -0,0 +1,251 @@
+use num_format::Locale;
+use serde::{Deserialize, Serialize};
+
+/// The widest word the calculator works with.
+pub const MAX_WIDTH: u32 = 32;
+
+/// A word of 1 to 32 bits, read as unsigned or as two's complement signed
+/// number. Bits above the width are always clear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Value {
+    bits: u32,
+    width: u32,
+    signed: bool,
+}
+
+/// A change of a [`Value`], as done by the keys of the calculator.
+///
+/// Serialized in snake case, e.g. `"not"` or `{"shift_left": 4}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Operation {
+    /// Replaces the value.
+    Set(u32),
+    ShiftLeft(u32),
+    ShiftRight(u32),
+    RotateLeft(u32),
+    RotateRight(u32),
+    Not,
+    And(u32),
+    Or(u32),
+    Xor(u32),
+    /// Sets or clears the bit of the given index.
+    SetBit(u32, bool),
+    ToggleBit(u32),
+}
+
+impl Value {
+    /// Creates a value, cutting the bits to the width, which is clamped to
+    /// 1 to [`MAX_WIDTH`].
+    pub fn new(bits: u32, width: u32, signed: bool) -> Self {
+        let width = width.clamp(1, MAX_WIDTH);
+        Self {
+            bits: bits & mask(width),
+            width,
+            signed,
+        }
+    }
+
+    /// The bits of the value.
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn signed(&self) -> bool {
+        self.signed
+    }
+
+    /// The same bits read as signed or unsigned.
+    #[must_use]
+    pub fn with_signed(self, signed: bool) -> Self {
+        Self { signed, ..self }
+    }
+
+    /// The value at another width. A signed value keeps its decimal when
+    /// widened, i.e. it is sign extended; narrowing cuts the high bits.
+    #[must_use]
+    pub fn with_width(self, width: u32) -> Self {
+        let bits = if self.signed {
+            self.decimal() as u32
+        } else {
+            self.bits
+        };
+        Self::new(bits, width, self.signed)
+    }
+
+    /// The value as decimal number: sign extended if it is signed.
+    pub fn decimal(&self) -> i64 {
+        if self.signed && self.bits >> (self.width - 1) & 1 == 1 {
+            self.bits as i64 - (1_i64 << self.width)
+        } else {
+            self.bits as i64
+        }
+    }
+
+    /// The value in the radix 2, 8, 10 or 16. Decimals are sign extended if
+    /// the value is signed, the other radixes give all digits of the width.
+    pub fn to_string_radix(&self, radix: u32) -> String {
+        let digits = |bits_per_digit: u32| self.width.div_ceil(bits_per_digit) as usize;
+        match radix {
+            2 => format!("{:0width$b}", self.bits, width = digits(1)),
+            8 => format!("{:0width$o}", self.bits, width = digits(3)),
+            16 => format!("{:0width$x}", self.bits, width = digits(4)),
+            _ => self.decimal().to_string(),
+        }
+    }
+
+    /// Applies the operation; bits shifted or rotated past the width are lost
+    /// or wrap around within the width.
+    #[must_use]
+    pub fn apply(self, operation: Operation) -> Self {
+        let width = self.width;
+        let bits = self.bits;
+        let rotate = |n: u32, left: bool| {
+            let n = n % width;
+            if n == 0 {
+                bits
+            } else if left {
+                bits << n | bits >> (width - n)
+            } else {
+                bits >> n | bits << (width - n)
+            }
+        };
+        let bits = match operation {
+            Operation::Set(value) => value,
+            Operation::ShiftLeft(n) => bits.checked_shl(n).unwrap_or(0),
+            Operation::ShiftRight(n) => bits.checked_shr(n).unwrap_or(0),
+            Operation::RotateLeft(n) => rotate(n, true),
+            Operation::RotateRight(n) => rotate(n, false),
+            Operation::Not => !bits,
+            Operation::And(value) => bits & value,
+            Operation::Or(value) => bits | value,
+            Operation::Xor(value) => bits ^ value,
+            Operation::SetBit(bit, true) => bits | 1_u32.checked_shl(bit).unwrap_or(0),
+            Operation::SetBit(bit, false) => bits & !1_u32.checked_shl(bit).unwrap_or(0),
+            Operation::ToggleBit(bit) => bits ^ 1_u32.checked_shl(bit).unwrap_or(0),
+        };
+        Self::new(bits, width, self.signed)
+    }
+}
+
+impl Default for Value {
+    fn default() -> Self {
+        Self::new(0, MAX_WIDTH, false)
+    }
+}
+
+/// The mask of the lowest `width` bits.
+pub fn mask(width: u32) -> u32 {
+    u32::MAX >> (MAX_WIDTH - width.clamp(1, MAX_WIDTH))
+}
+
+/// Reads a typed decimal grouped by the separator of `locale`, ignoring
+/// white space, with the minus sign of the locale or `-`. Negative numbers
+/// give their two's complement, numbers with a decimal point are rejected.
+pub fn parse_decimal(input: &str, locale: &Locale) -> Option<u32> {
+    let input = input.trim();
+    let (negative, input) = match input
+        .strip_prefix(locale.minus_sign())
+        .or_else(|| input.strip_prefix('-'))
+    {
+        Some(digits) => (true, digits),
+        None => (false, input),
+    };
+    let digits: String = input
+        .replace(locale.separator(), "")
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    if digits.is_empty() {
+        return (!negative).then_some(0);
+    }
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    if negative {
+        format!("-{digits}")
+            .parse::<i32>()
+            .ok()
+            .map(|value| value as u32)
+    } else {
+        digits.parse().ok()
+    }
+}
+
+/// Reads typed hexadecimal digits, ignoring `.`, `,` and spaces between them.
+pub fn parse_hexadecimal(input: &str) -> Option<u32> {
+    let digits = input.replace(['.', ',', ' '], "");
+    if digits.is_empty() {
+        return Some(0);
+    }
+    u32::from_str_radix(&digits, 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply() {
+        let word = Value::new(0x0f, 8, false);
+        assert_eq!(word.apply(Operation::ShiftLeft(4)).bits(), 0xf0);
+        assert_eq!(word.apply(Operation::ShiftLeft(8)).bits(), 0);
+        assert_eq!(word.apply(Operation::ShiftLeft(40)).bits(), 0);
+        assert_eq!(word.apply(Operation::ShiftRight(2)).bits(), 0x03);
+        assert_eq!(word.apply(Operation::RotateLeft(6)).bits(), 0xc3);
+        assert_eq!(word.apply(Operation::RotateRight(1)).bits(), 0x87);
+        assert_eq!(word.apply(Operation::RotateLeft(8)).bits(), 0x0f);
+        assert_eq!(word.apply(Operation::Not).bits(), 0xf0);
+        assert_eq!(word.apply(Operation::And(0x3c)).bits(), 0x0c);
+        assert_eq!(word.apply(Operation::Or(0x1f0)).bits(), 0xff);
+        assert_eq!(word.apply(Operation::Xor(0xff)).bits(), 0xf0);
+        assert_eq!(word.apply(Operation::SetBit(7, true)).bits(), 0x8f);
+        assert_eq!(word.apply(Operation::SetBit(0, false)).bits(), 0x0e);
+        assert_eq!(word.apply(Operation::SetBit(40, true)).bits(), 0x0f);
+        assert_eq!(word.apply(Operation::ToggleBit(4)).bits(), 0x1f);
+        assert_eq!(word.apply(Operation::Set(0x1234)).bits(), 0x34);
+    }
+
+    #[test]
+    fn to_string_radix() {
+        let word = Value::new(0x1f, 8, false);
+        assert_eq!(word.to_string_radix(2), "00011111");
+        assert_eq!(word.to_string_radix(8), "037");
+        assert_eq!(word.to_string_radix(10), "31");
+        assert_eq!(word.to_string_radix(16), "1f");
+        let word = Value::new(0xff, 8, true);
+        assert_eq!(word.to_string_radix(10), "-1");
+        assert_eq!(word.to_string_radix(16), "ff");
+        assert_eq!(Value::new(0x8000_0000, 32, true).decimal(), -(1 << 31));
+        assert_eq!(Value::new(0x1f, 5, false).to_string_radix(16), "1f");
+    }
+
+    #[test]
+    fn with_width() {
+        let byte = Value::new(0xf0, 8, true);
+        assert_eq!(byte.with_width(16).bits(), 0xfff0);
+        assert_eq!(byte.with_width(16).decimal(), -16);
+        assert_eq!(byte.with_width(4).bits(), 0x0);
+        assert_eq!(byte.with_signed(false).with_width(16).bits(), 0xf0);
+        assert_eq!(Value::new(0x1234, 16, true).with_width(8).bits(), 0x34);
+    }
+
+    #[test]
+    fn parse_decimal_with_locale() {
+        assert_eq!(parse_decimal("1,234,567", &Locale::en), Some(1_234_567));
+        assert_eq!(parse_decimal("1.234.567", &Locale::de), Some(1_234_567));
+        assert_eq!(parse_decimal("-3", &Locale::en), Some(-3_i32 as u32));
+        assert_eq!(
+            parse_decimal(&format!("{}3", Locale::sv.minus_sign()), &Locale::sv),
+            Some(-3_i32 as u32)
+        );
+        assert_eq!(parse_decimal("", &Locale::en), Some(0));
+        assert_eq!(parse_decimal("-", &Locale::en), None);
+        assert_eq!(parse_decimal("1.5", &Locale::en), None);
+        assert_eq!(parse_decimal("1,5", &Locale::de), None);
+    }
+}
@@ -0,0 +1,38 @@
+//! Checks that every translation defines the same keys, so no language
+//! silently falls back to English.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+/// The message identifiers of a Fluent file: the lines starting with
+/// `identifier =`.
+fn keys(path: &Path) -> BTreeSet<String> {
+    fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, _)| key)
+        .filter(|key| !key.starts_with([' ', '#', '.']))
+        .map(|key| key.trim().to_string())
+        .collect()
+}
+
+#[test]
+fn all_locales_have_the_same_keys() {
+    let locales: Vec<_> = fs::read_dir("locales")
+        .unwrap()
+        .map(|entry| entry.unwrap().path().join("main.ftl"))
+        .collect();
+    let english = keys(Path::new("locales/en/main.ftl"));
+    for locale in &locales {
+        let other = keys(locale);
+        let missing: Vec<_> = english.difference(&other).collect();
+        let extra: Vec<_> = other.difference(&english).collect();
+        assert!(
+            missing.is_empty() && extra.is_empty(),
+            "{}: missing {missing:?}, not in English {extra:?}",
+            locale.display()
+        );
+    }
+}